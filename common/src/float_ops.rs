@@ -88,18 +88,37 @@ pub fn floordiv(v1: f64, v2: f64) -> Option<f64> {
     }
 }
 
+/// CPython's `float.__divmod__` algorithm (see `float_divmod` in
+/// `floatobject.c`): the remainder always takes the divisor's sign, and the
+/// quotient is snapped to the nearest integer to compensate for the
+/// remainder computation being an approximation in floating point.
 pub fn divmod(v1: f64, v2: f64) -> Option<(f64, f64)> {
-    if v2 != 0.0 {
-        let mut m = v1 % v2;
-        let mut d = (v1 - m) / v2;
+    if v2 == 0.0 {
+        return None;
+    }
+    let mut m = v1 % v2;
+    let mut d = (v1 - m) / v2;
+    if m != 0.0 {
         if v2.is_sign_negative() != m.is_sign_negative() {
             m += v2;
             d -= 1.0;
         }
-        Some((d, m))
     } else {
-        None
+        // `v1 % v2` can come back either signed zero regardless of v2, so
+        // pin it to the divisor's sign like CPython does.
+        m = m.copysign(v2);
     }
+
+    let floordiv = if d != 0.0 {
+        let floor = d.floor();
+        if d - floor > 0.5 { floor + 1.0 } else { floor }
+    } else {
+        // d rounded to exactly zero -- give it the sign of the true,
+        // unrounded quotient instead of always-positive zero.
+        0.0_f64.copysign(v1 / v2)
+    };
+
+    Some((floordiv, m))
 }
 
 // nextafter algorithm based off of https://gitlab.com/bronsonbdevost/next_afterf
@@ -265,3 +284,46 @@ pub fn round_float_digits(x: f64, ndigits: i32) -> Option<f64> {
     };
     Some(float)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_divmod_matches_cpython() {
+        // Expected values are CPython's actual `divmod()` results.
+        let cases: &[(f64, f64, f64, f64)] = &[
+            (-7.0, 3.0, -3.0, 2.0),
+            (7.0, -3.0, -3.0, -2.0),
+            (2.0, 4.0, 0.0, 2.0),
+            (-2.0, 4.0, -1.0, 2.0),
+            (2.0, -4.0, -1.0, -2.0),
+            (1.0, f64::INFINITY, 0.0, 1.0),
+            (-1.0, f64::INFINITY, -1.0, f64::INFINITY),
+            (1.0, f64::NEG_INFINITY, -1.0, f64::NEG_INFINITY),
+            (-1.0, f64::NEG_INFINITY, 0.0, -1.0),
+            (0.0, 5.0, 0.0, 0.0),
+        ];
+        for &(v1, v2, expected_div, expected_mod) in cases {
+            let (div, m) = divmod(v1, v2).unwrap();
+            assert_eq!(div, expected_div, "divmod({v1}, {v2}) quotient");
+            assert_eq!(m, expected_mod, "divmod({v1}, {v2}) remainder");
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_divmod_negative_zero() {
+        // divmod(-0.0, 5.0) == (-0.0, 0.0) in CPython: the quotient keeps
+        // -0.0's sign, and the zero remainder takes the divisor's sign.
+        let (div, m) = divmod(-0.0, 5.0).unwrap();
+        assert!(div.is_sign_negative() && div == 0.0);
+        assert!(m.is_sign_positive() && m == 0.0);
+    }
+
+    #[test]
+    fn test_divmod_by_zero() {
+        assert_eq!(divmod(1.0, 0.0), None);
+    }
+}
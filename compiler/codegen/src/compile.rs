@@ -1865,12 +1865,9 @@ impl Compiler {
                     });
 
                     if let Some(expr) = &bound {
-                        let scope_name = if expr.is_tuple_expr() {
-                            format!("<TypeVar constraint of {name}>")
-                        } else {
-                            format!("<TypeVar bound of {name}>")
-                        };
-                        self.compile_type_param_bound_or_default(expr, &scope_name, false)?;
+                        // CPython names this scope after the type parameter itself,
+                        // not after what it's bounding.
+                        self.compile_type_param_bound_or_default(expr, name.as_str(), false)?;
 
                         let intrinsic = if expr.is_tuple_expr() {
                             bytecode::IntrinsicFunction2::TypeVarWithConstraint
@@ -1889,8 +1886,11 @@ impl Compiler {
 
                     // Handle default value if present (PEP 695)
                     if let Some(default_expr) = default {
-                        let scope_name = format!("<TypeVar default of {name}>");
-                        self.compile_type_param_bound_or_default(default_expr, &scope_name, false)?;
+                        self.compile_type_param_bound_or_default(
+                            default_expr,
+                            name.as_str(),
+                            false,
+                        )?;
                         emit!(
                             self,
                             Instruction::CallIntrinsic2 {
@@ -1915,8 +1915,11 @@ impl Compiler {
 
                     // Handle default value if present (PEP 695)
                     if let Some(default_expr) = default {
-                        let scope_name = format!("<ParamSpec default of {name}>");
-                        self.compile_type_param_bound_or_default(default_expr, &scope_name, false)?;
+                        self.compile_type_param_bound_or_default(
+                            default_expr,
+                            name.as_str(),
+                            false,
+                        )?;
                         emit!(
                             self,
                             Instruction::CallIntrinsic2 {
@@ -1942,8 +1945,11 @@ impl Compiler {
                     // Handle default value if present (PEP 695)
                     if let Some(default_expr) = default {
                         // TypeVarTuple allows starred expressions
-                        let scope_name = format!("<TypeVarTuple default of {name}>");
-                        self.compile_type_param_bound_or_default(default_expr, &scope_name, true)?;
+                        self.compile_type_param_bound_or_default(
+                            default_expr,
+                            name.as_str(),
+                            true,
+                        )?;
                         emit!(
                             self,
                             Instruction::CallIntrinsic2 {
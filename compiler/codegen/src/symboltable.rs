@@ -20,11 +20,25 @@ use ruff_python_ast::{
 };
 use ruff_text_size::{Ranged, TextRange};
 use rustpython_compiler_core::{SourceFile, SourceLocation};
-use std::{borrow::Cow, fmt};
+use std::{
+    borrow::Cow,
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Assigns each [`SymbolTable`] a process-wide unique id at construction
+/// time, mirroring CPython's `symtable.SymbolTable.get_id()` (which returns
+/// the C-level block's address): the id survives `Clone`, so the same
+/// underlying block reached via different paths (e.g. `get_children()` vs.
+/// `lookup(...).get_namespaces()`) always reports the same id.
+static NEXT_TABLE_ID: AtomicUsize = AtomicUsize::new(1);
 
 /// Captures all symbols in the current scope, and has a list of sub-scopes in this scope.
 #[derive(Clone)]
 pub struct SymbolTable {
+    /// A unique identifier for this symbol table, stable across clones.
+    pub id: usize,
+
     /// The name of this symbol table. Often the name of the class or function.
     pub name: String,
 
@@ -60,6 +74,7 @@ pub struct SymbolTable {
 impl SymbolTable {
     fn new(name: String, typ: CompilerScope, line_number: u32, is_nested: bool) -> Self {
         Self {
+            id: NEXT_TABLE_ID.fetch_add(1, Ordering::Relaxed),
             name,
             typ,
             line_number,
@@ -109,13 +124,17 @@ impl fmt::Display for CompilerScope {
             Self::Function => write!(f, "function"),
             Self::AsyncFunction => write!(f, "async function"),
             Self::Lambda => write!(f, "lambda"),
-            Self::Comprehension => write!(f, "comprehension"),
-            Self::TypeParams => write!(f, "type parameter"),
-            // TODO missing types from the C implementation
+            // CPython's symtable module has no dedicated comprehension type --
+            // comprehensions/genexprs are their own function-shaped blocks, and
+            // report "function" just like a `def`.
+            Self::Comprehension => write!(f, "function"),
+            Self::TypeParams => write!(f, "type parameters"),
+            // TODO missing types from the C implementation (CPython 3.13 gives these
+            // their own symtable entry type; we fold them into TypeParams for now)
             // if self._table.type == _symtable.TYPE_ANNOTATION:
             //     return "annotation"
             // if self._table.type == _symtable.TYPE_TYPE_VAR_BOUND:
-            //     return "TypeVar bound"
+            //     return "type variable"
             // if self._table.type == _symtable.TYPE_TYPE_ALIAS:
             //     return "type alias"
         }
@@ -770,7 +789,7 @@ impl SymbolTableBuilder {
                 }
                 if let Some(type_params) = type_params {
                     self.enter_type_param_block(
-                        &format!("<generic parameters of {}>", name.as_str()),
+                        name.as_str(),
                         self.line_index_start(type_params.range),
                     )?;
                     self.scan_type_params(type_params)?;
@@ -796,7 +815,7 @@ impl SymbolTableBuilder {
             }) => {
                 if let Some(type_params) = type_params {
                     self.enter_type_param_block(
-                        &format!("<generic parameters of {}>", name.as_str()),
+                        name.as_str(),
                         self.line_index_start(type_params.range),
                     )?;
                     self.scan_type_params(type_params)?;
@@ -985,8 +1004,11 @@ impl SymbolTableBuilder {
                 ..
             }) => {
                 if let Some(type_params) = type_params {
+                    let alias_name = name
+                        .as_name_expr()
+                        .map_or("<type alias>", |n| n.id.as_str());
                     self.enter_type_param_block(
-                        "TypeAlias",
+                        alias_name,
                         self.line_index_start(type_params.range),
                     )?;
                     self.scan_type_params(type_params)?;
@@ -1399,20 +1421,15 @@ impl SymbolTableBuilder {
                 }) => {
                     self.register_name(name.as_str(), SymbolUsage::TypeParam, *type_var_range)?;
 
-                    // Process bound in a separate scope
+                    // Process bound in a separate scope. CPython names this scope after
+                    // the type parameter itself, not after what it's bounding.
                     if let Some(binding) = bound {
-                        let scope_name = if binding.is_tuple_expr() {
-                            format!("<TypeVar constraint of {name}>")
-                        } else {
-                            format!("<TypeVar bound of {name}>")
-                        };
-                        self.scan_type_param_bound_or_default(binding, &scope_name)?;
+                        self.scan_type_param_bound_or_default(binding, name.as_str())?;
                     }
 
                     // Process default in a separate scope
                     if let Some(default_value) = default {
-                        let scope_name = format!("<TypeVar default of {name}>");
-                        self.scan_type_param_bound_or_default(default_value, &scope_name)?;
+                        self.scan_type_param_bound_or_default(default_value, name.as_str())?;
                     }
                 }
                 TypeParam::ParamSpec(TypeParamParamSpec {
@@ -1424,8 +1441,7 @@ impl SymbolTableBuilder {
 
                     // Process default in a separate scope
                     if let Some(default_value) = default {
-                        let scope_name = format!("<ParamSpec default of {name}>");
-                        self.scan_type_param_bound_or_default(default_value, &scope_name)?;
+                        self.scan_type_param_bound_or_default(default_value, name)?;
                     }
                 }
                 TypeParam::TypeVarTuple(TypeParamTypeVarTuple {
@@ -1437,8 +1453,7 @@ impl SymbolTableBuilder {
 
                     // Process default in a separate scope
                     if let Some(default_value) = default {
-                        let scope_name = format!("<TypeVarTuple default of {name}>");
-                        self.scan_type_param_bound_or_default(default_value, &scope_name)?;
+                        self.scan_type_param_bound_or_default(default_value, name)?;
                     }
                 }
             }
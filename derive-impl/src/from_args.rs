@@ -20,7 +20,7 @@ impl TryFrom<&Ident> for ParameterKind {
 
     fn try_from(ident: &Ident) -> std::result::Result<Self, Self::Error> {
         Ok(match ident.to_string().as_str() {
-            "positional" => Self::PositionalOnly,
+            "positional" | "positional_only" => Self::PositionalOnly,
             "any" => Self::PositionalOrKeyword,
             "named" => Self::KeywordOnly,
             "flatten" => Self::Flatten,
@@ -56,7 +56,8 @@ impl ArgAttribute {
                         .ok_or_else(|| {
                             meta.error(
                                 "The first argument to #[pyarg()] must be the parameter type, \
-                                 either 'positional', 'any', 'named', or 'flatten'.",
+                                 either 'positional' (or its alias 'positional_only'), 'any', \
+                                 'named', or 'flatten'.",
                             )
                         })?;
                     arg_attr = Some(Self {
@@ -120,6 +121,22 @@ impl TryFrom<&Field> for ArgAttribute {
     }
 }
 
+/// The Python-visible name a field's `#[pyarg]` binds to, or `None` for a
+/// `_phantom`/flatten field that isn't a parameter in its own right.
+fn field_pyname(field: &Field, attr: &ArgAttribute) -> Result<Option<String>> {
+    let name_string = field.ident.as_ref().map(|ident| ident.unraw().to_string());
+    if matches!(&name_string, Some(s) if s.starts_with("_phantom"))
+        || matches!(attr.kind, ParameterKind::Flatten)
+    {
+        return Ok(None);
+    }
+    attr.name
+        .clone()
+        .or(name_string)
+        .map(Some)
+        .ok_or_else(|| err_span!(field, "field in tuple struct must have name attribute"))
+}
+
 fn generate_field((i, field): (usize, &Field)) -> Result<TokenStream> {
     let attr = ArgAttribute::try_from(field)?;
     let name = field.ident.as_ref();
@@ -141,9 +158,7 @@ fn generate_field((i, field): (usize, &Field)) -> Result<TokenStream> {
         });
     }
 
-    let pyname = attr
-        .name
-        .or(name_string)
+    let pyname = field_pyname(field, &attr)?
         .ok_or_else(|| err_span!(field, "field in tuple struct must have name attribute"))?;
 
     let middle = quote! {
@@ -206,18 +221,43 @@ fn compute_arity_bounds(field_attrs: &[ArgAttribute]) -> (usize, usize) {
 }
 
 pub fn impl_from_args(input: DeriveInput) -> Result<TokenStream> {
-    let (fields, field_attrs) = match input.data {
-        Data::Struct(syn::DataStruct { fields, .. }) => (
-            fields
+    let (fields, field_attrs, posonly_pynames, flatten_field_types) = match input.data {
+        Data::Struct(syn::DataStruct { fields, .. }) => {
+            let field_attrs = fields
+                .iter()
+                .filter_map(|field| ArgAttribute::try_from(field).ok())
+                .collect::<Vec<ArgAttribute>>();
+            let posonly_pynames = fields
                 .iter()
-                .enumerate()
-                .map(generate_field)
-                .collect::<Result<TokenStream>>()?,
-            fields
+                .filter_map(|field| {
+                    let attr = ArgAttribute::try_from(field).ok()?;
+                    if !matches!(attr.kind, ParameterKind::PositionalOnly) {
+                        return None;
+                    }
+                    field_pyname(field, &attr).ok().flatten()
+                })
+                .collect::<Vec<String>>();
+            // A `flatten` field delegates its own binding to another
+            // `FromArgs` impl, so any positional-only parameters it declares
+            // need to be picked up from there too.
+            let flatten_field_types = fields
                 .iter()
-                .filter_map(|field| field.try_into().ok())
-                .collect::<Vec<ArgAttribute>>(),
-        ),
+                .filter_map(|field| {
+                    let attr = ArgAttribute::try_from(field).ok()?;
+                    matches!(attr.kind, ParameterKind::Flatten).then(|| field.ty.clone())
+                })
+                .collect::<Vec<_>>();
+            (
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(generate_field)
+                    .collect::<Result<TokenStream>>()?,
+                field_attrs,
+                posonly_pynames,
+                flatten_field_types,
+            )
+        }
         _ => bail_span!(input, "FromArgs input must be a struct"),
     };
 
@@ -231,6 +271,12 @@ pub fn impl_from_args(input: DeriveInput) -> Result<TokenStream> {
                 #min_arity..=#max_arity
             }
 
+            fn posonly_names() -> ::std::vec::Vec<&'static str> {
+                let mut names: ::std::vec::Vec<&'static str> = ::std::vec![#(#posonly_pynames),*];
+                #(names.extend(<#flatten_field_types as ::rustpython_vm::function::FromArgs>::posonly_names());)*
+                names
+            }
+
             fn from_args(
                 vm: &::rustpython_vm::VirtualMachine,
                 args: &mut ::rustpython_vm::function::FuncArgs
@@ -0,0 +1,28 @@
+//! A plugin that reports a deliberately wrong ABI version, hand-written
+//! (rather than via `declare_plugin!`) so `tests/load_plugin.rs` can exercise
+//! `native_plugin::load`'s ABI-mismatch rejection path.
+
+use rustpython_plugin::vm::pymodule;
+
+#[pymodule]
+mod bad_abi_plugin {
+    #[pyfunction]
+    fn greet() -> String {
+        "should never be reachable".to_owned()
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _rustpython_plugin_entry()
+-> rustpython_plugin::vm::stdlib::native_plugin::PluginEntry {
+    extern "C" fn init(
+        vm: &rustpython_plugin::vm::VirtualMachine,
+    ) -> rustpython_plugin::vm::PyRef<rustpython_plugin::vm::builtins::PyModule> {
+        bad_abi_plugin::make_module(vm)
+    }
+
+    rustpython_plugin::vm::stdlib::native_plugin::PluginEntry {
+        abi_version: c"not-a-real-version".as_ptr(),
+        init,
+    }
+}
@@ -0,0 +1,16 @@
+//! Minimal in-tree example of a native plugin, built as this example's
+//! `cdylib` artifact (see `plugin/Cargo.toml`). Exercised end-to-end by
+//! `tests/load_plugin.rs`, which builds this example and loads it through
+//! `rustpython_vm::stdlib::native_plugin::load`.
+
+use rustpython_plugin::vm::pymodule;
+
+#[pymodule]
+mod hello_plugin {
+    #[pyfunction]
+    fn greet() -> String {
+        "hello from a native plugin".to_owned()
+    }
+}
+
+rustpython_plugin::declare_plugin!(hello_plugin::make_module);
@@ -0,0 +1,61 @@
+//! Helper crate for writing RustPython native plugins: cdylibs loaded at
+//! runtime by [`rustpython_vm::stdlib::native_plugin::load`] (in turn driven
+//! by `_imp.create_dynamic`). A plugin is an ordinary [`#[pymodule]`] built
+//! with the same tooling as an in-tree stdlib module; [`declare_plugin!`]
+//! generates the `extern "C"` entry point the loader looks for so you don't
+//! have to hand-write the FFI boilerplate or the ABI-version check.
+//!
+//! ```ignore
+//! use rustpython_vm::pymodule;
+//!
+//! #[pymodule]
+//! mod hello_plugin {
+//!     use rustpython_vm::VirtualMachine;
+//!
+//!     #[pyfunction]
+//!     fn greet() -> String {
+//!         "hello from a native plugin".to_owned()
+//!     }
+//! }
+//!
+//! rustpython_plugin::declare_plugin!(hello_plugin::make_module);
+//! ```
+//!
+//! Build the crate exposing this as a `cdylib`, give the resulting shared
+//! library the platform's `_imp.extension_suffixes()` suffix (`.rpyd`), and
+//! `import` it from RustPython like any other extension module.
+
+#[cfg(not(all(
+    any(target_os = "linux", target_os = "macos", target_os = "windows"),
+    not(any(target_env = "musl", target_env = "sgx"))
+)))]
+compile_error!(
+    "rustpython-plugin targets the same platforms as rustpython_vm::stdlib::native_plugin \
+     (linux/macos/windows, not musl or sgx); native plugins aren't supported elsewhere"
+);
+
+pub use rustpython_vm as vm;
+
+/// Generate the `extern "C"` entry point a plugin cdylib must export, from a
+/// `#[pymodule]`-style `make_module(&VirtualMachine) -> PyRef<PyModule>`
+/// function. See the crate-level docs for a full example.
+#[macro_export]
+macro_rules! declare_plugin {
+    ($make_module:path) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn _rustpython_plugin_entry()
+        -> $crate::vm::stdlib::native_plugin::PluginEntry {
+            extern "C" fn init(
+                vm: &$crate::vm::VirtualMachine,
+            ) -> $crate::vm::PyRef<$crate::vm::builtins::PyModule> {
+                $make_module(vm)
+            }
+
+            $crate::vm::stdlib::native_plugin::PluginEntry {
+                abi_version: $crate::vm::stdlib::native_plugin::ABI_VERSION_CSTR.as_ptr()
+                    as *const ::std::os::raw::c_char,
+                init,
+            }
+        }
+    };
+}
@@ -0,0 +1,89 @@
+//! Builds the in-tree `examples/hello_plugin.rs` as a cdylib and loads it
+//! through `native_plugin::load`, exercising the dlopen/symbol-lookup/
+//! ABI-check/init path end-to-end rather than by code inspection alone.
+
+use rustpython_plugin::vm::stdlib::native_plugin;
+use rustpython_plugin::vm::{Interpreter, PyResult};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn cdylib_name(stem: &str) -> String {
+    if cfg!(target_os = "macos") {
+        format!("lib{stem}.dylib")
+    } else if cfg!(windows) {
+        format!("{stem}.dll")
+    } else {
+        format!("lib{stem}.so")
+    }
+}
+
+/// Builds `example` into a scratch target dir shared by both tests (so the
+/// path to each resulting cdylib is known exactly, rather than guessed from
+/// the workspace's own `target/`) and returns the path to the built library.
+fn build_example_plugin(example: &str) -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = std::env::temp_dir().join("rustpython-plugin-test-target");
+
+    let status = Command::new(env!("CARGO"))
+        .current_dir(manifest_dir)
+        .args(["build", "--example", example, "--target-dir"])
+        .arg(&target_dir)
+        .status()
+        .unwrap_or_else(|e| panic!("failed to spawn cargo to build example {example}: {e}"));
+    assert!(status.success(), "building example {example} failed");
+
+    target_dir
+        .join("debug")
+        .join("examples")
+        .join(cdylib_name(example))
+}
+
+#[test]
+fn build_and_load_example_plugin() {
+    let lib_path = build_example_plugin("hello_plugin");
+    assert!(
+        lib_path.is_file(),
+        "expected a cdylib at {}",
+        lib_path.display()
+    );
+
+    Interpreter::without_stdlib(Default::default())
+        .enter(|vm| -> PyResult<()> {
+            let module = native_plugin::load("hello_plugin", lib_path.to_str().unwrap(), vm)
+                .unwrap_or_else(|e| panic!("plugin failed to load: {e}"));
+            let greet = module.get_attr("greet", vm)?;
+            let result = greet.call((), vm)?;
+            let text = result.str(vm)?.as_str().to_owned();
+            assert_eq!(text, "hello from a native plugin");
+
+            // Loading the same plugin path again should still succeed: the
+            // loader dlopens it again and re-runs its `init`, while the first
+            // `libloading::Library` handle stays alive; `_imp.create_dynamic`'s
+            // own sys.modules cache check is what actually skips this in practice.
+            let module2 = native_plugin::load("hello_plugin", lib_path.to_str().unwrap(), vm)
+                .unwrap_or_else(|e| panic!("re-loading the plugin failed: {e}"));
+            assert!(module2.get_attr("greet", vm).is_ok());
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn rejects_a_plugin_with_a_mismatched_abi_version() {
+    let lib_path = build_example_plugin("bad_abi_plugin");
+    assert!(
+        lib_path.is_file(),
+        "expected a cdylib at {}",
+        lib_path.display()
+    );
+
+    Interpreter::without_stdlib(Default::default()).enter(|vm| {
+        let err = native_plugin::load("bad_abi_plugin", lib_path.to_str().unwrap(), vm)
+            .expect_err("a plugin reporting the wrong ABI version should be rejected");
+        assert!(
+            err.contains("not-a-real-version"),
+            "expected the mismatch error to name the plugin's reported version, got: {err}"
+        );
+    });
+}
@@ -171,11 +171,26 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode) -> PyResult<()> {
     }
 
     let site_result = vm.import("site", 0);
-    if site_result.is_err() {
-        warn!(
-            "Failed to import site, consider adding the Lib directory to your RUSTPYTHONPATH \
-             environment variable",
-        );
+    match site_result {
+        Ok(site) => {
+            // `site.main()` (sys.path setup) already self-skips under `-S`
+            // (`sys.flags.no_site`), but that also means it never calls
+            // `setquit`/`setcopyright`/`sethelper`, leaving `help`, `exit`,
+            // `quit`, `copyright`, `credits` and `license` undefined even in
+            // embedded/`-S` setups. Call them ourselves so they're always
+            // available, independent of whether `site.main()` ran.
+            for setup_fn in ["setquit", "setcopyright", "sethelper"] {
+                if vm.call_method(&site, setup_fn, ()).is_err() {
+                    warn!("Failed to run site.{setup_fn}()");
+                }
+            }
+        }
+        Err(_) => {
+            warn!(
+                "Failed to import site, consider adding the Lib directory to your RUSTPYTHONPATH \
+                 environment variable",
+            );
+        }
     }
 
     let is_repl = matches!(run_mode, RunMode::Repl);
@@ -213,6 +228,13 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode) -> PyResult<()> {
         RunMode::Repl => Ok(()),
     };
     if is_repl || vm.state.settings.inspect {
+        // -i (or a bare REPL invocation) drops into the shell even if the
+        // script raised; print the exception first so it's not silently
+        // swallowed, and so sys.last_exc/last_traceback get set for a
+        // subsequent `import pdb; pdb.pm()`.
+        if let Err(exc) = res {
+            vm.print_exception(exc);
+        }
         shell::run_shell(vm, scope)?;
     } else {
         res?;
@@ -299,4 +321,37 @@ mod tests {
             })());
         })
     }
+
+    /// A `# -*- coding: cp1251 -*-` cookie should make `run_script` decode
+    /// the file with that codec instead of assuming UTF-8, per PEP 263.
+    #[test]
+    fn test_run_script_coding_cookie() {
+        interpreter().enter(|vm| {
+            vm.unwrap_pyresult((|| {
+                let scope = setup_main_module(vm)?;
+                vm.run_script(
+                    scope,
+                    "extra_tests/snippets/encoding_fixtures/cp1251_literal.py",
+                )?;
+                Ok(())
+            })());
+        })
+    }
+
+    /// A leading UTF-8 BOM should be stripped and the rest of the file
+    /// decoded as UTF-8, per PEP 263, instead of the BOM bytes corrupting
+    /// the first token or the source failing to decode at all.
+    #[test]
+    fn test_run_script_utf8_sig_bom() {
+        interpreter().enter(|vm| {
+            vm.unwrap_pyresult((|| {
+                let scope = setup_main_module(vm)?;
+                vm.run_script(
+                    scope,
+                    "extra_tests/snippets/encoding_fixtures/utf8_sig_literal.py",
+                )?;
+                Ok(())
+            })());
+        })
+    }
 }
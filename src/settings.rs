@@ -219,6 +219,20 @@ pub fn parse_opts() -> Result<(Settings, RunMode), lexopt::Error> {
     if !ignore_environment {
         settings.path_list.extend(get_paths("RUSTPYTHONPATH"));
         settings.path_list.extend(get_paths("PYTHONPATH"));
+
+        settings.home = env::var("RUSTPYTHONHOME")
+            .or_else(|_| env::var("PYTHONHOME"))
+            .ok()
+            .filter(|home| !home.is_empty());
+        if let Some(home) = &settings.home {
+            let lib_dir = std::path::Path::new(home).join("lib").join(format!(
+                "rustpython{}",
+                rustpython_vm::version::get_winver_number()
+            ));
+            if let Some(lib_dir) = lib_dir.to_str() {
+                settings.path_list.push(lib_dir.to_owned());
+            }
+        }
     }
 
     // Now process command line flags:
@@ -243,7 +257,9 @@ pub fn parse_opts() -> Result<(Settings, RunMode), lexopt::Error> {
     settings.write_bytecode = !(args.dont_write_bytecode || env_bool("PYTHONDONTWRITEBYTECODE"));
     settings.safe_path = settings.isolated || args.safe_path || env_bool("PYTHONSAFEPATH");
     settings.inspect = args.inspect || env_bool("PYTHONINSPECT");
-    settings.interactive = args.inspect;
+    // A bare REPL invocation is interactive on its own; `-i` makes an
+    // otherwise non-interactive run (a script, `-c`, `-m`) interactive too.
+    settings.interactive = args.inspect || matches!(mode, RunMode::Repl);
     settings.buffered_stdio = !args.unbuffered;
 
     if let Some(val) = get_env("PYTHONINTMAXSTRDIGITS") {
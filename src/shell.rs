@@ -8,6 +8,7 @@ use rustpython_vm::{
     AsObject, PyResult, VirtualMachine,
     builtins::PyBaseExceptionRef,
     compiler::{self},
+    identifier,
     readline::{Readline, ReadlineResult},
     scope::Scope,
 };
@@ -108,8 +109,42 @@ fn shell_exec(
     }
 }
 
+/// Run the file named by `$PYTHONSTARTUP`, if set, in the interactive
+/// namespace before the first prompt. A missing/unreadable file or a Python
+/// exception raised while running it is reported but doesn't stop the REPL
+/// from starting, matching CPython.
+fn run_startup_file(vm: &VirtualMachine, scope: &Scope) {
+    let path = match std::env::var("PYTHONSTARTUP") {
+        Ok(path) if !path.is_empty() => path,
+        _ => return,
+    };
+    let source = match std::fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("Could not open PYTHONSTARTUP file '{path}': {err}");
+            return;
+        }
+    };
+    // CPython doesn't leave `__file__` pointing at the startup script once
+    // it's done running, so save/restore whatever was there before (nothing,
+    // for a plain REPL; the script's own `__file__`, for `-i script.py`).
+    let prev_file = scope
+        .globals
+        .get_item_opt(identifier!(vm, __file__), vm)
+        .ok()
+        .flatten();
+    if let Err(exc) = vm.run_code_string(scope.clone(), &source, path) {
+        vm.print_exception(exc);
+    }
+    let _ = match prev_file {
+        Some(prev) => scope.globals.set_item(identifier!(vm, __file__), prev, vm),
+        None => scope.globals.del_item(identifier!(vm, __file__), vm),
+    };
+}
+
 /// Enter a repl loop
 pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
+    run_startup_file(vm, &scope);
     let mut repl = Readline::new(helper::ShellHelper::new(vm, scope.globals.clone()));
     let mut full_input = String::new();
 
@@ -5,21 +5,20 @@ mod decl {
     use crate::vm::{
         PyObjectRef, PyRef, PyResult, TryFromObject, VirtualMachine,
         builtins::{PyCode, PyDictRef, PyStrRef},
-        bytecode::CodeFlags,
+        bytecode::{self, CodeFlags, InstrDisplayContext},
+        convert::ToPyObject,
+        types::PyStructSequence,
     };
 
-    #[pyfunction]
-    fn dis(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
-        let co = if let Ok(co) = obj.get_attr("__code__", vm) {
+    fn to_code(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<PyRef<PyCode>> {
+        if let Ok(co) = obj.get_attr("__code__", vm) {
             // Method or function:
-            PyRef::try_from_object(vm, co)?
+            PyRef::try_from_object(vm, co)
         } else if let Ok(co_str) = PyStrRef::try_from_object(vm, obj.clone()) {
             #[cfg(not(feature = "compiler"))]
             {
                 let _ = co_str;
-                return Err(
-                    vm.new_runtime_error("dis.dis() with str argument requires `compiler` feature")
-                );
+                Err(vm.new_runtime_error("dis.dis() with str argument requires `compiler` feature"))
             }
             #[cfg(feature = "compiler")]
             {
@@ -28,12 +27,16 @@ mod decl {
                     crate::vm::compiler::Mode::Exec,
                     "<dis>".to_owned(),
                 )
-                .map_err(|err| vm.new_syntax_error(&err, Some(co_str.as_str())))?
+                .map_err(|err| vm.new_syntax_error(&err, Some(co_str.as_str())))
             }
         } else {
-            PyRef::try_from_object(vm, obj)?
-        };
-        disassemble(co)
+            PyRef::try_from_object(vm, obj)
+        }
+    }
+
+    #[pyfunction]
+    fn dis(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        disassemble(to_code(obj, vm)?)
     }
 
     #[pyfunction]
@@ -42,6 +45,202 @@ mod decl {
         Ok(())
     }
 
+    /// `dis.Instruction`: a single decoded instruction, modeled after
+    /// CPython's namedtuple of the same name.
+    #[pyattr]
+    #[pyclass(module = "dis", name = "Instruction")]
+    #[derive(PyStructSequence)]
+    struct DisInstruction {
+        opname: String,
+        opcode: u8,
+        arg: Option<u32>,
+        argval: PyObjectRef,
+        argrepr: String,
+        offset: usize,
+        starts_line: Option<usize>,
+        is_jump_target: bool,
+    }
+
+    #[pyclass(with(PyStructSequence))]
+    impl DisInstruction {}
+
+    /// Decode `instruction`'s raw operand into a `(arg, argval, argrepr)`
+    /// triple, resolving name/const/jump-target references against `code`.
+    fn decode_arg<C: bytecode::Constant + Clone + Into<PyObjectRef>>(
+        instruction: bytecode::Instruction,
+        op_arg: bytecode::OpArg,
+        code: &bytecode::CodeObject<C>,
+        vm: &VirtualMachine,
+    ) -> (Option<u32>, PyObjectRef, String) {
+        use bytecode::Instruction::*;
+
+        let name_arg = |idx: bytecode::Arg<bytecode::NameIdx>| {
+            let i = idx.get(op_arg);
+            let name = code.get_name(i as usize).to_owned();
+            (Some(i), vm.ctx.new_str(name.clone()).into(), name)
+        };
+        let varname_arg = |idx: bytecode::Arg<bytecode::NameIdx>| {
+            let i = idx.get(op_arg);
+            let name = code.get_varname(i as usize).to_owned();
+            (Some(i), vm.ctx.new_str(name.clone()).into(), name)
+        };
+        let cellname_arg = |idx: bytecode::Arg<bytecode::NameIdx>| {
+            let i = idx.get(op_arg);
+            let name = code.get_cell_name(i as usize).to_owned();
+            (Some(i), vm.ctx.new_str(name.clone()).into(), name)
+        };
+        let jump_arg = |target: bytecode::Arg<bytecode::Label>| {
+            let offset = target.get(op_arg).0;
+            (
+                Some(offset),
+                vm.ctx.new_int(offset).into(),
+                format!("to {offset}"),
+            )
+        };
+        let int_arg = |raw: u32| (Some(raw), vm.ctx.new_int(raw).into(), raw.to_string());
+        let debug_arg = |repr: String| (None, vm.ctx.new_str(repr.clone()).into(), repr);
+        let no_arg = || (None, vm.ctx.none(), String::new());
+
+        match instruction {
+            ImportName { idx } | ImportFrom { idx } => name_arg(idx),
+            LoadNameAny(idx) | LoadGlobal(idx) | StoreLocal(idx) | StoreGlobal(idx)
+            | DeleteLocal(idx) | DeleteGlobal(idx) => name_arg(idx),
+            StoreAttr { idx } | DeleteAttr { idx } | LoadAttr { idx } | LoadMethod { idx } => {
+                name_arg(idx)
+            }
+            LoadFast(idx) | StoreFast(idx) | DeleteFast(idx) => varname_arg(idx),
+            LoadDeref(idx) | LoadClassDeref(idx) | StoreDeref(idx) | DeleteDeref(idx)
+            | LoadClosure(idx) => cellname_arg(idx),
+            LoadConst { idx } | ReturnConst { idx } => {
+                let i = idx.get(op_arg);
+                let value: PyObjectRef = code.get_constant(i as usize).clone().into();
+                let repr = value
+                    .repr(vm)
+                    .map(|s| s.as_str().to_owned())
+                    .unwrap_or_default();
+                (Some(i), value, repr)
+            }
+            Continue { target }
+            | Break { target }
+            | Jump { target }
+            | JumpIfTrue { target }
+            | JumpIfFalse { target }
+            | JumpIfTrueOrPop { target }
+            | JumpIfFalseOrPop { target }
+            | ForIter { target }
+            | SetupFinally { handler: target }
+            | SetupExcept { handler: target }
+            | SetupWith { end: target }
+            | SetupAsyncWith { end: target } => jump_arg(target),
+            UnaryOperation { op } => debug_arg(format!("{:?}", op.get(op_arg))),
+            BinaryOperation { op } | BinaryOperationInplace { op } => {
+                debug_arg(format!("{:?}", op.get(op_arg)))
+            }
+            TestOperation { op } => debug_arg(format!("{:?}", op.get(op_arg))),
+            CompareOperation { op } => debug_arg(format!("{:?}", op.get(op_arg))),
+            Raise { kind } => debug_arg(format!("{:?}", kind.get(op_arg))),
+            FormatValue { conversion } => debug_arg(format!("{:?}", conversion.get(op_arg))),
+            CallIntrinsic1 { func } => debug_arg(format!("{:?}", func.get(op_arg))),
+            CallIntrinsic2 { func } => debug_arg(format!("{:?}", func.get(op_arg))),
+            SetFunctionAttribute { attr } => debug_arg(format!("{:?}", attr.get(op_arg))),
+            CopyItem { index } | Swap { index } => int_arg(index.get(op_arg)),
+            CallFunctionPositional { nargs } | CallFunctionKeyword { nargs } => {
+                int_arg(nargs.get(op_arg))
+            }
+            CallMethodPositional { nargs } | CallMethodKeyword { nargs } => {
+                int_arg(nargs.get(op_arg))
+            }
+            CallFunctionEx { has_kwargs } | CallMethodEx { has_kwargs } => {
+                let has_kwargs = has_kwargs.get(op_arg);
+                (
+                    Some(has_kwargs as u32),
+                    vm.ctx.new_bool(has_kwargs).into(),
+                    has_kwargs.to_string(),
+                )
+            }
+            Resume { arg } => int_arg(arg.get(op_arg)),
+            BuildString { size }
+            | BuildTuple { size }
+            | BuildTupleFromTuples { size }
+            | BuildList { size }
+            | BuildListFromTuples { size }
+            | BuildSet { size }
+            | BuildSetFromTuples { size }
+            | BuildMap { size }
+            | BuildMapForCall { size }
+            | UnpackSequence { size } => int_arg(size.get(op_arg)),
+            BuildSlice { step } => {
+                let step = step.get(op_arg);
+                (
+                    Some(step as u32),
+                    vm.ctx.new_bool(step).into(),
+                    step.to_string(),
+                )
+            }
+            ListAppend { i } | SetAdd { i } | MapAdd { i } => int_arg(i.get(op_arg)),
+            UnpackEx { args } => {
+                let args = args.get(op_arg);
+                (None, vm.ctx.none(), format!("{args}"))
+            }
+            Reverse { amount } => int_arg(amount.get(op_arg)),
+            MatchClass(arg) => int_arg(arg.get(op_arg)),
+            _ => no_arg(),
+        }
+    }
+
+    fn get_instructions_vec(co: &PyRef<PyCode>, vm: &VirtualMachine) -> Vec<DisInstruction> {
+        let code = &co.code;
+        let label_targets = code.label_targets();
+        let mut arg_state = bytecode::OpArgState::default();
+        let mut last_line = None;
+        code.instructions
+            .iter()
+            .enumerate()
+            .map(|(offset, &unit)| {
+                let (instruction, op_arg) = arg_state.get(unit);
+                let opcode: u8 = instruction.into();
+                let debug_repr = format!("{instruction:?}");
+                let opname = debug_repr
+                    .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .next()
+                    .unwrap_or_default()
+                    .to_owned();
+
+                let line = code.locations[offset].row.get();
+                let starts_line = if last_line != Some(line) {
+                    last_line = Some(line);
+                    Some(line)
+                } else {
+                    None
+                };
+
+                let is_jump_target = label_targets.contains(&bytecode::Label(offset as u32));
+
+                let (arg, argval, argrepr) = decode_arg(instruction, op_arg, code, vm);
+
+                DisInstruction {
+                    opname,
+                    opcode,
+                    arg,
+                    argval,
+                    argrepr,
+                    offset,
+                    starts_line,
+                    is_jump_target,
+                }
+            })
+            .collect()
+    }
+
+    #[pyfunction]
+    fn get_instructions(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+        let co = to_code(obj, vm)?;
+        Ok(get_instructions_vec(&co, vm)
+            .into_iter()
+            .map(|instr| instr.to_pyobject(vm))
+            .collect())
+    }
+
     #[pyattr(name = "COMPILER_FLAG_NAMES")]
     fn compiler_flag_names(vm: &VirtualMachine) -> PyDictRef {
         let dict = vm.ctx.new_dict();
@@ -3,6 +3,16 @@ pub(crate) use gc::make_module;
 #[pymodule]
 mod gc {
     use crate::vm::{PyResult, VirtualMachine, function::FuncArgs};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // RustPython has no cycle-collecting garbage collector -- objects are
+    // freed by reference counting alone -- so `collect()` never finds
+    // anything and this flag doesn't gate any real collection trigger. It
+    // exists so callers like `timeit` that bracket a benchmark with
+    // `gc.disable()`/`gc.enable()` succeed instead of raising, and so
+    // `isenabled()` reflects the last call instead of always reporting
+    // `False`.
+    static ENABLED: AtomicBool = AtomicBool::new(true);
 
     #[pyfunction]
     fn collect(_args: FuncArgs, _vm: &VirtualMachine) -> i32 {
@@ -11,17 +21,19 @@ mod gc {
 
     #[pyfunction]
     fn isenabled(_args: FuncArgs, _vm: &VirtualMachine) -> bool {
-        false
+        ENABLED.load(Ordering::Relaxed)
     }
 
     #[pyfunction]
-    fn enable(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn enable(_args: FuncArgs, _vm: &VirtualMachine) -> PyResult<()> {
+        ENABLED.store(true, Ordering::Relaxed);
+        Ok(())
     }
 
     #[pyfunction]
-    fn disable(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn disable(_args: FuncArgs, _vm: &VirtualMachine) -> PyResult<()> {
+        ENABLED.store(false, Ordering::Relaxed);
+        Ok(())
     }
 
     #[pyfunction]
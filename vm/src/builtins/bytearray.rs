@@ -29,6 +29,7 @@ use crate::{
         BufferDescriptor, BufferMethods, BufferResizeGuard, PyBuffer, PyIterReturn,
         PyMappingMethods, PyNumberMethods, PySequenceMethods,
     },
+    sequence::SequenceMutExt,
     sliceable::{SequenceIndex, SliceableSequenceMutOp, SliceableSequenceOp},
     types::{
         AsBuffer, AsMapping, AsNumber, AsSequence, Callable, Comparable, Constructor,
@@ -212,7 +213,7 @@ impl PyByteArray {
 
     #[pymethod]
     fn __sizeof__(&self) -> usize {
-        size_of::<Self>() + self.borrow_buf().len() * size_of::<u8>()
+        size_of::<Self>() + self.inner().capacity() * size_of::<u8>()
     }
 
     #[pymethod]
@@ -584,6 +585,7 @@ impl Py<PyByteArray> {
     fn insert(&self, index: isize, object: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
         let value = value_from_object(vm, &object)?;
         let elements = &mut self.try_resizable(vm)?.elements;
+        elements.reserve_cpython_style(1);
         let index = elements.saturate_index(index);
         elements.insert(index, value);
         Ok(())
@@ -592,7 +594,9 @@ impl Py<PyByteArray> {
     #[pymethod]
     fn append(&self, object: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
         let value = value_from_object(vm, &object)?;
-        self.try_resizable(vm)?.elements.push(value);
+        let elements = &mut self.try_resizable(vm)?.elements;
+        elements.reserve_cpython_style(1);
+        elements.push(value);
         Ok(())
     }
 
@@ -613,7 +617,9 @@ impl Py<PyByteArray> {
             PyByteArray::irepeat(self, 2, vm)
         } else {
             let items = bytes_from_object(vm, &object)?;
-            self.try_resizable(vm)?.elements.extend(items);
+            let elements = &mut self.try_resizable(vm)?.elements;
+            elements.reserve_cpython_style(items.len());
+            elements.extend(items);
             Ok(())
         }
     }
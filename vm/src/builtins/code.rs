@@ -129,7 +129,11 @@ impl ConstantBag for PyObjBag<'_> {
             bytecode::BorrowedConstant::Str { value } if value.len() <= 20 => {
                 ctx.intern_str(value).to_object()
             }
-            bytecode::BorrowedConstant::Str { value } => ctx.new_str(value).into(),
+            // Longer string constants aren't worth interning forever, but
+            // are still worth sharing: reuse a still-live equal `str` (e.g.
+            // from an identical constant in another code object) instead of
+            // allocating a fresh one every time.
+            bytecode::BorrowedConstant::Str { value } => ctx.cache_constant_str(value),
             bytecode::BorrowedConstant::Bytes { value } => ctx.new_bytes(value.to_vec()).into(),
             bytecode::BorrowedConstant::Boolean { value } => ctx.new_bool(value).into(),
             bytecode::BorrowedConstant::Code { code } => {
@@ -1,12 +1,10 @@
-use super::{
-    IterStatus, PositionIterInternal, PyGenericAlias, PyIntRef, PyTupleRef, PyType, PyTypeRef,
-};
+use super::{IterStatus, PositionIterInternal, PyGenericAlias, PyTupleRef, PyType, PyTypeRef};
 use crate::common::lock::{PyMutex, PyRwLock};
 use crate::{
     AsObject, Context, Py, PyObjectRef, PyPayload, PyResult, VirtualMachine,
     class::PyClassImpl,
     convert::ToPyObject,
-    function::OptionalArg,
+    function::{ArgIndex, OptionalArg},
     protocol::{PyIter, PyIterReturn},
     raise_if_stop,
     types::{Constructor, IterNext, Iterable, SelfIter},
@@ -34,7 +32,7 @@ pub struct EnumerateArgs {
     #[pyarg(any)]
     iterable: PyIter,
     #[pyarg(any, optional)]
-    start: OptionalArg<PyIntRef>,
+    start: OptionalArg<ArgIndex>,
 }
 
 impl Constructor for PyEnumerate {
@@ -2,18 +2,23 @@
 
 */
 
-use super::{PyCode, PyDictRef, PyIntRef, PyStrRef};
+use super::{PyCode, PyDictRef, PyIntRef, PyStr, PyStrRef, PyType};
 use crate::{
-    AsObject, Context, Py, PyObjectRef, PyRef, PyResult, VirtualMachine,
+    AsObject, Context, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
+    atomic_func,
+    bytecode::CodeFlags,
     class::PyClassImpl,
     frame::{Frame, FrameRef},
-    function::PySetterValue,
-    types::{Representable, Unconstructible},
+    function::{OptionalArg, PySetterValue},
+    protocol::{PyMappingMethods, PySequenceMethods},
+    types::{AsMapping, AsSequence, Iterable, Representable, Unconstructible},
 };
 use num_traits::Zero;
+use std::sync::LazyLock;
 
 pub fn init(context: &Context) {
     Frame::extend_class(context, context.types.frame_type);
+    FrameLocalsProxy::extend_class(context, context.types.frame_locals_proxy_type);
 }
 
 impl Unconstructible for Frame {}
@@ -44,8 +49,8 @@ impl Frame {
     }
 
     #[pygetset]
-    fn f_locals(&self, vm: &VirtualMachine) -> PyResult {
-        self.locals(vm).map(Into::into)
+    fn f_builtins(&self) -> PyDictRef {
+        self.builtins.clone()
     }
 
     #[pygetset]
@@ -123,4 +128,249 @@ impl Py<Frame> {
             .nth(1)
             .cloned()
     }
+
+    #[pygetset]
+    fn f_locals(&self, vm: &VirtualMachine) -> PyResult {
+        // PEP 667: `locals()` (see `Frame::locals`) is a disconnected
+        // snapshot for an optimized (function) frame, but `f_locals` must
+        // stay a live, write-through view onto that frame's variables --
+        // that's what `FrameLocalsProxy` provides. Module and class bodies
+        // have no fast locals at all, so their namespace dict (what
+        // `Frame::locals` already returns unchanged for them) is itself
+        // live and needs no wrapping.
+        if self.code.flags.contains(CodeFlags::IS_OPTIMIZED) {
+            Ok(FrameLocalsProxy::new(self.to_owned()).into_pyobject(vm))
+        } else {
+            self.locals(vm).map(Into::into)
+        }
+    }
+}
+
+/// Identifies where a name lives in a running, optimized frame.
+enum LocalSlot {
+    /// Index into `Frame::fastlocals`.
+    Fast(usize),
+    /// Index into `Frame::cells_frees`.
+    Cell(usize),
+}
+
+fn resolve_local_slot(frame: &Frame, name: &str) -> Option<LocalSlot> {
+    let code = &**frame.code;
+    if let Some(i) = code.varnames.iter().position(|v| v.as_str() == name) {
+        return Some(LocalSlot::Fast(i));
+    }
+    if let Some(i) = code.cellvars.iter().position(|v| v.as_str() == name) {
+        return Some(LocalSlot::Cell(i));
+    }
+    if code.flags.contains(CodeFlags::IS_OPTIMIZED) {
+        if let Some(i) = code.freevars.iter().position(|v| v.as_str() == name) {
+            return Some(LocalSlot::Cell(code.cellvars.len() + i));
+        }
+    }
+    None
+}
+
+/// `frame.f_locals` for an optimized (function) frame: CPython's
+/// `FrameLocalsProxy`. Unlike `locals()`, reads and writes of names that
+/// correspond to one of the frame's fast locals/cells/frees go straight to
+/// the running frame rather than a snapshot, so e.g. a debugger can mutate
+/// a local variable through this mapping and see it take effect. Names
+/// that aren't one of the frame's own variables fall back to a plain dict
+/// stashed on the frame itself (`Frame::extra_locals`), matching CPython's
+/// "extra locals" behavior.
+#[pyclass(module = false, name = "FrameLocalsProxy")]
+#[derive(Debug)]
+pub struct FrameLocalsProxy {
+    frame: FrameRef,
+}
+
+impl PyPayload for FrameLocalsProxy {
+    #[inline]
+    fn class(ctx: &Context) -> &'static Py<PyType> {
+        ctx.types.frame_locals_proxy_type
+    }
+}
+
+impl FrameLocalsProxy {
+    fn new(frame: FrameRef) -> Self {
+        Self { frame }
+    }
+
+    fn extra(&self) -> Option<PyDictRef> {
+        self.frame.extra_locals.lock().clone()
+    }
+
+    fn get_inner(&self, key: &PyObject, vm: &VirtualMachine) -> PyResult<Option<PyObjectRef>> {
+        if let Some(name) = key.downcast_ref::<PyStr>() {
+            if let Some(slot) = resolve_local_slot(&self.frame, name.as_str()) {
+                let value = match slot {
+                    LocalSlot::Fast(i) => self.frame.fastlocals.lock()[i].clone(),
+                    LocalSlot::Cell(i) => self.frame.cells_frees[i].get(),
+                };
+                return Ok(value);
+            }
+        }
+        match self.extra() {
+            Some(extra) => extra.get_item_opt(key, vm),
+            None => Ok(None),
+        }
+    }
+
+    /// A fresh dict combining the frame's current variables (same rules as
+    /// `Frame::locals`) with any extra names stashed via this proxy --
+    /// used for the bulk views (`__len__`, `__iter__`, `keys`, ...), which
+    /// in CPython are themselves snapshots even though single-item access
+    /// through the proxy is live.
+    fn to_dict(&self, vm: &VirtualMachine) -> PyResult<PyDictRef> {
+        let dict: PyDictRef = PyObjectRef::from(self.frame.locals(vm)?)
+            .downcast()
+            .unwrap_or_else(|_| unreachable!("Frame::locals always builds a plain dict here"));
+        if let Some(extra) = self.extra() {
+            for (k, v) in extra {
+                dict.set_item(&*k, v, vm)?;
+            }
+        }
+        Ok(dict)
+    }
+}
+
+#[pyclass(with(AsMapping, AsSequence, Iterable, Representable, Unconstructible))]
+impl FrameLocalsProxy {
+    #[pymethod]
+    fn __getitem__(&self, key: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        self.get_inner(&key, vm)?
+            .ok_or_else(|| vm.new_key_error(key))
+    }
+
+    #[pymethod]
+    fn get(&self, key: PyObjectRef, default: OptionalArg, vm: &VirtualMachine) -> PyResult {
+        Ok(self
+            .get_inner(&key, vm)?
+            .unwrap_or_else(|| default.unwrap_or_none(vm)))
+    }
+
+    #[pymethod]
+    fn __setitem__(
+        &self,
+        key: PyObjectRef,
+        value: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        if let Some(name) = key.downcast_ref::<PyStr>() {
+            if let Some(slot) = resolve_local_slot(&self.frame, name.as_str()) {
+                match slot {
+                    LocalSlot::Fast(i) => self.frame.fastlocals.lock()[i] = Some(value),
+                    LocalSlot::Cell(i) => self.frame.cells_frees[i].set(Some(value)),
+                }
+                return Ok(());
+            }
+        }
+        let mut extra = self.frame.extra_locals.lock();
+        let extra = extra.get_or_insert_with(|| vm.ctx.new_dict());
+        extra.set_item(&*key, value, vm)
+    }
+
+    #[pymethod]
+    fn __delitem__(&self, key: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        if let Some(name) = key.downcast_ref::<PyStr>() {
+            if let Some(slot) = resolve_local_slot(&self.frame, name.as_str()) {
+                let bound = match &slot {
+                    LocalSlot::Fast(i) => self.frame.fastlocals.lock()[*i].is_some(),
+                    LocalSlot::Cell(i) => self.frame.cells_frees[*i].get().is_some(),
+                };
+                if !bound {
+                    return Err(vm.new_key_error(key));
+                }
+                // CPython refuses this too: a frame's own local variables
+                // can't be unbound through the proxy without unwinding the
+                // interpreter's own view of which locals are live.
+                return Err(
+                    vm.new_value_error("cannot remove local variables from FrameLocalsProxy")
+                );
+            }
+        }
+        match self.extra() {
+            Some(extra) => extra.del_item(&*key, vm),
+            None => Err(vm.new_key_error(key)),
+        }
+    }
+
+    #[pymethod]
+    fn __contains__(&self, key: PyObjectRef, vm: &VirtualMachine) -> PyResult<bool> {
+        Ok(self.get_inner(&key, vm)?.is_some())
+    }
+
+    #[pymethod]
+    fn __len__(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        Ok(self.to_dict(vm)?.len())
+    }
+
+    #[pymethod]
+    fn keys(&self, vm: &VirtualMachine) -> PyResult {
+        Ok(self.to_dict(vm)?.keys().into_pyobject(vm))
+    }
+
+    #[pymethod]
+    fn values(&self, vm: &VirtualMachine) -> PyResult {
+        Ok(self.to_dict(vm)?.values().into_pyobject(vm))
+    }
+
+    #[pymethod]
+    fn items(&self, vm: &VirtualMachine) -> PyResult {
+        Ok(self.to_dict(vm)?.items().into_pyobject(vm))
+    }
+
+    #[pymethod]
+    fn copy(&self, vm: &VirtualMachine) -> PyResult<PyDictRef> {
+        self.to_dict(vm)
+    }
+}
+
+impl Unconstructible for FrameLocalsProxy {}
+
+impl Representable for FrameLocalsProxy {
+    #[inline]
+    fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
+        Ok(zelf.to_dict(vm)?.repr(vm)?.as_str().to_owned())
+    }
+}
+
+impl AsMapping for FrameLocalsProxy {
+    fn as_mapping() -> &'static PyMappingMethods {
+        static AS_MAPPING: LazyLock<PyMappingMethods> = LazyLock::new(|| PyMappingMethods {
+            length: atomic_func!(
+                |mapping, vm| FrameLocalsProxy::mapping_downcast(mapping).__len__(vm)
+            ),
+            subscript: atomic_func!(|mapping, needle, vm| {
+                FrameLocalsProxy::mapping_downcast(mapping).__getitem__(needle.to_owned(), vm)
+            }),
+            ass_subscript: atomic_func!(|mapping, needle, value, vm| {
+                let zelf = FrameLocalsProxy::mapping_downcast(mapping);
+                match value {
+                    Some(value) => zelf.__setitem__(needle.to_owned(), value, vm),
+                    None => zelf.__delitem__(needle.to_owned(), vm),
+                }
+            }),
+        });
+        &AS_MAPPING
+    }
+}
+
+impl AsSequence for FrameLocalsProxy {
+    fn as_sequence() -> &'static PySequenceMethods {
+        static AS_SEQUENCE: LazyLock<PySequenceMethods> = LazyLock::new(|| PySequenceMethods {
+            contains: atomic_func!(|seq, target, vm| FrameLocalsProxy::sequence_downcast(seq)
+                .__contains__(target.to_owned(), vm)),
+            ..PySequenceMethods::NOT_IMPLEMENTED
+        });
+        &AS_SEQUENCE
+    }
+}
+
+impl Iterable for FrameLocalsProxy {
+    fn iter(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyResult {
+        let dict: PyObjectRef = zelf.to_dict(vm)?.into();
+        let iter = dict.get_iter(vm)?;
+        Ok(iter.into())
+    }
 }
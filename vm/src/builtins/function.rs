@@ -708,6 +708,14 @@ pub struct PyBoundMethod {
     function: PyObjectRef,
 }
 
+impl PyBoundMethod {
+    /// The underlying callable this method is bound to, e.g. so callers can
+    /// see through the wrapper to classify the call it actually performs.
+    pub(crate) fn function(&self) -> &PyObjectRef {
+        &self.function
+    }
+}
+
 impl Callable for PyBoundMethod {
     type Args = FuncArgs;
     #[inline]
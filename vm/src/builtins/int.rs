@@ -409,6 +409,10 @@ impl PyInt {
         if modulus.is_zero() {
             return Err(vm.new_value_error("pow() 3rd argument cannot be 0"));
         }
+        // CPython gives a negative modulus the sign of the result (matching
+        // `%`); work with the magnitude below and fix the sign up at the end.
+        let negative_modulus = modulus.is_negative();
+        let modulus = modulus.abs();
 
         self.general_op(
             other,
@@ -429,13 +433,18 @@ impl PyInt {
                             None
                         }
                     }
-                    let a = inverse(a % modulus, modulus).ok_or_else(|| {
+                    let a = inverse(a % &modulus, &modulus).ok_or_else(|| {
                         vm.new_value_error("base is not invertible for the given modulus")
                     })?;
                     let b = -b;
-                    a.modpow(&b, modulus)
+                    a.modpow(&b, &modulus)
                 } else {
-                    a.modpow(b, modulus)
+                    a.modpow(b, &modulus)
+                };
+                let i = if negative_modulus && !i.is_zero() {
+                    i - &modulus
+                } else {
+                    i
                 };
                 Ok(vm.ctx.new_int(i).into())
             },
@@ -113,19 +113,24 @@ pub type PyListRef = PyRef<PyList>;
 impl PyList {
     #[pymethod]
     pub(crate) fn append(&self, x: PyObjectRef) {
-        self.borrow_vec_mut().push(x);
+        let mut elements = self.borrow_vec_mut();
+        elements.reserve_cpython_style(1);
+        elements.push(x);
     }
 
     #[pymethod]
     pub(crate) fn extend(&self, x: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
         let mut new_elements = x.try_to_value(vm)?;
-        self.borrow_vec_mut().append(&mut new_elements);
+        let mut elements = self.borrow_vec_mut();
+        elements.reserve_cpython_style(new_elements.len());
+        elements.append(&mut new_elements);
         Ok(())
     }
 
     #[pymethod]
     pub(crate) fn insert(&self, position: isize, element: PyObjectRef) {
         let mut elements = self.borrow_vec_mut();
+        elements.reserve_cpython_style(1);
         let position = elements.saturate_index(position);
         elements.insert(position, element);
     }
@@ -154,7 +159,9 @@ impl PyList {
         vm: &VirtualMachine,
     ) -> PyResult<PyObjectRef> {
         let mut seq = extract_cloned(other, Ok, vm)?;
-        zelf.borrow_vec_mut().append(&mut seq);
+        let mut elements = zelf.borrow_vec_mut();
+        elements.reserve_cpython_style(seq.len());
+        elements.append(&mut seq);
         Ok(zelf.to_owned().into())
     }
 
@@ -165,7 +172,9 @@ impl PyList {
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<Self>> {
         let mut seq = extract_cloned(&other, Ok, vm)?;
-        zelf.borrow_vec_mut().append(&mut seq);
+        let mut elements = zelf.borrow_vec_mut();
+        elements.reserve_cpython_style(seq.len());
+        elements.append(&mut seq);
         Ok(zelf)
     }
 
@@ -324,7 +333,15 @@ impl PyList {
         // this prevents keyfunc from messing with the list and makes it easy to
         // check if it tries to append elements to it.
         let mut elements = std::mem::take(self.borrow_vec_mut().deref_mut());
+        // if a key func or comparison raises partway through, restore the
+        // pre-sort order rather than exposing whatever partial permutation
+        // the sort algorithm had reached -- matches CPython, which sorts a
+        // saved copy for exactly this reason.
+        let original = elements.clone();
         let res = do_sort(vm, &mut elements, options.key, options.reverse);
+        if res.is_err() {
+            elements = original;
+        }
         std::mem::swap(self.borrow_vec_mut().deref_mut(), &mut elements);
         res?;
 
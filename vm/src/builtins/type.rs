@@ -1339,12 +1339,20 @@ impl Py<PyType> {
     }
 
     #[pymethod]
-    fn __dir__(&self) -> PyList {
-        let attributes: Vec<PyObjectRef> = self
-            .get_attributes()
-            .into_iter()
-            .map(|(k, _)| k.to_object())
-            .collect();
+    fn __dir__(zelf: &Py<Self>) -> PyList {
+        // Merge the class's own MRO attributes with those reachable through
+        // the metaclass's MRO, mirroring CPython's type___dir___impl.
+        let mut attributes = zelf.get_attributes();
+        for meta_cls in std::iter::once(zelf.class())
+            .chain(zelf.class().mro.read().iter().map(|cls| -> &Self { cls }))
+        {
+            for (name, value) in meta_cls.attributes.read().iter() {
+                attributes.entry(name.to_owned()).or_insert(value.clone());
+            }
+        }
+
+        let attributes: Vec<PyObjectRef> =
+            attributes.into_iter().map(|(k, _)| k.to_object()).collect();
         PyList::from(attributes)
     }
 
@@ -3,16 +3,58 @@ use crate::{
     Context, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine, atomic_func,
     class::PyClassImpl,
     common::hash::PyHash,
-    function::{OptionalArg, PyComparisonValue, PySetterValue},
+    function::{FuncArgs, OptionalArg, PyComparisonValue, PySetterValue},
     protocol::{PyIter, PyIterReturn, PyMappingMethods, PySequenceMethods},
     stdlib::builtins::reversed,
     types::{
-        AsMapping, AsSequence, Comparable, Constructor, GetAttr, Hashable, IterNext, Iterable,
-        PyComparisonOp, Representable, SetAttr,
+        AsMapping, AsSequence, Callable, Comparable, Constructor, GetAttr, Hashable, IterNext,
+        Iterable, PyComparisonOp, Representable, SetAttr,
     },
 };
 use std::sync::LazyLock;
 
+fn new_reference_error(vm: &VirtualMachine) -> PyRef<super::PyBaseException> {
+    vm.new_exception_msg(
+        vm.ctx.exceptions.reference_error.to_owned(),
+        "weakly-referenced object no longer exists".to_owned(),
+    )
+}
+
+fn try_upgrade(weak: &PyRef<PyWeak>, vm: &VirtualMachine) -> PyResult {
+    weak.upgrade().ok_or_else(|| new_reference_error(vm))
+}
+
+#[derive(FromArgs)]
+pub struct WeakProxyNewArgs {
+    #[pyarg(positional)]
+    pub(crate) referent: PyObjectRef,
+    #[pyarg(positional, optional)]
+    pub(crate) callback: OptionalArg<PyObjectRef>,
+}
+
+crate::common::static_cell! {
+    static WEAK_SUBCLASS: PyTypeRef;
+}
+
+fn downgrade_for_proxy(
+    referent: PyObjectRef,
+    callback: OptionalArg<PyObjectRef>,
+    vm: &VirtualMachine,
+) -> PyResult<PyRef<PyWeak>> {
+    // using an internal subclass as the class prevents us from getting the generic weakref,
+    // which would mess up the weakref count
+    let weak_cls = WEAK_SUBCLASS.get_or_init(|| {
+        vm.ctx.new_class(
+            None,
+            "__weakproxy",
+            vm.ctx.types.weakref_type.to_owned(),
+            super::PyWeak::make_slots(),
+        )
+    });
+    // TODO: PyWeakProxy should use the same payload as PyWeak
+    referent.downgrade_with_typ(callback.into_option(), weak_cls.clone(), vm)
+}
+
 #[pyclass(module = false, name = "weakproxy", unhashable = true, traverse)]
 #[derive(Debug)]
 pub struct PyWeakProxy {
@@ -26,14 +68,6 @@ impl PyPayload for PyWeakProxy {
     }
 }
 
-#[derive(FromArgs)]
-pub struct WeakProxyNewArgs {
-    #[pyarg(positional)]
-    referent: PyObjectRef,
-    #[pyarg(positional, optional)]
-    callback: OptionalArg<PyObjectRef>,
-}
-
 impl Constructor for PyWeakProxy {
     type Args = WeakProxyNewArgs;
 
@@ -42,29 +76,14 @@ impl Constructor for PyWeakProxy {
         Self::Args { referent, callback }: Self::Args,
         vm: &VirtualMachine,
     ) -> PyResult {
-        // using an internal subclass as the class prevents us from getting the generic weakref,
-        // which would mess up the weakref count
-        let weak_cls = WEAK_SUBCLASS.get_or_init(|| {
-            vm.ctx.new_class(
-                None,
-                "__weakproxy",
-                vm.ctx.types.weakref_type.to_owned(),
-                super::PyWeak::make_slots(),
-            )
-        });
-        // TODO: PyWeakProxy should use the same payload as PyWeak
         Self {
-            weak: referent.downgrade_with_typ(callback.into_option(), weak_cls.clone(), vm)?,
+            weak: downgrade_for_proxy(referent, callback, vm)?,
         }
         .into_ref_with_type(vm, cls)
         .map(Into::into)
     }
 }
 
-crate::common::static_cell! {
-    static WEAK_SUBCLASS: PyTypeRef;
-}
-
 #[pyclass(with(
     GetAttr,
     SetAttr,
@@ -77,7 +96,7 @@ crate::common::static_cell! {
 ))]
 impl PyWeakProxy {
     fn try_upgrade(&self, vm: &VirtualMachine) -> PyResult {
-        self.weak.upgrade().ok_or_else(|| new_reference_error(vm))
+        try_upgrade(&self.weak, vm)
     }
 
     #[pymethod]
@@ -144,13 +163,6 @@ impl IterNext for PyWeakProxy {
     }
 }
 
-fn new_reference_error(vm: &VirtualMachine) -> PyRef<super::PyBaseException> {
-    vm.new_exception_msg(
-        vm.ctx.exceptions.reference_error.to_owned(),
-        "weakly-referenced object no longer exists".to_owned(),
-    )
-}
-
 impl GetAttr for PyWeakProxy {
     // TODO: callbacks
     fn getattro(zelf: &Py<Self>, name: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
@@ -230,12 +242,228 @@ impl Representable for PyWeakProxy {
     }
 }
 
-pub fn init(context: &Context) {
-    PyWeakProxy::extend_class(context, context.types.weakproxy_type);
+impl Hashable for PyWeakProxy {
+    fn hash(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyHash> {
+        zelf.try_upgrade(vm)?.hash(vm)
+    }
 }
 
-impl Hashable for PyWeakProxy {
+/// Like `PyWeakProxy`, but for a callable referent: `weakref.proxy()` hands
+/// one of these out instead of a plain `weakproxy` whenever the referent
+/// supports `__call__`, same as CPython's `weakcallableproxy`. Everything
+/// but the extra `__call__` delegation is identical to `PyWeakProxy`, so it
+/// shares the same helpers rather than re-deriving them.
+#[pyclass(
+    module = false,
+    name = "weakcallableproxy",
+    unhashable = true,
+    traverse
+)]
+#[derive(Debug)]
+pub struct PyWeakCallableProxy {
+    weak: PyRef<PyWeak>,
+}
+
+impl PyPayload for PyWeakCallableProxy {
+    #[inline]
+    fn class(ctx: &Context) -> &'static Py<PyType> {
+        ctx.types.weakcallableproxy_type
+    }
+}
+
+impl Constructor for PyWeakCallableProxy {
+    type Args = WeakProxyNewArgs;
+
+    fn py_new(
+        cls: PyTypeRef,
+        Self::Args { referent, callback }: Self::Args,
+        vm: &VirtualMachine,
+    ) -> PyResult {
+        Self {
+            weak: downgrade_for_proxy(referent, callback, vm)?,
+        }
+        .into_ref_with_type(vm, cls)
+        .map(Into::into)
+    }
+}
+
+#[pyclass(with(
+    GetAttr,
+    SetAttr,
+    Constructor,
+    Comparable,
+    AsSequence,
+    AsMapping,
+    Representable,
+    IterNext,
+    Callable
+))]
+impl PyWeakCallableProxy {
+    fn try_upgrade(&self, vm: &VirtualMachine) -> PyResult {
+        try_upgrade(&self.weak, vm)
+    }
+
+    #[pymethod]
+    fn __str__(&self, vm: &VirtualMachine) -> PyResult<PyStrRef> {
+        self.try_upgrade(vm)?.str(vm)
+    }
+
+    fn len(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        self.try_upgrade(vm)?.length(vm)
+    }
+
+    #[pymethod]
+    fn __bool__(&self, vm: &VirtualMachine) -> PyResult<bool> {
+        self.try_upgrade(vm)?.is_true(vm)
+    }
+
+    #[pymethod]
+    fn __bytes__(&self, vm: &VirtualMachine) -> PyResult {
+        self.try_upgrade(vm)?.bytes(vm)
+    }
+
+    #[pymethod]
+    fn __reversed__(&self, vm: &VirtualMachine) -> PyResult {
+        let obj = self.try_upgrade(vm)?;
+        reversed(obj, vm)
+    }
+    #[pymethod]
+    fn __contains__(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult<bool> {
+        self.try_upgrade(vm)?.to_sequence().contains(&needle, vm)
+    }
+
+    fn getitem(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let obj = self.try_upgrade(vm)?;
+        obj.get_item(&*needle, vm)
+    }
+
+    fn setitem(
+        &self,
+        needle: PyObjectRef,
+        value: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let obj = self.try_upgrade(vm)?;
+        obj.set_item(&*needle, value, vm)
+    }
+
+    fn delitem(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        let obj = self.try_upgrade(vm)?;
+        obj.del_item(&*needle, vm)
+    }
+}
+
+impl Iterable for PyWeakCallableProxy {
+    fn iter(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyResult {
+        let obj = zelf.try_upgrade(vm)?;
+        Ok(obj.get_iter(vm)?.into())
+    }
+}
+
+impl IterNext for PyWeakCallableProxy {
+    fn next(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
+        let obj = zelf.try_upgrade(vm)?;
+        PyIter::new(obj).next(vm)
+    }
+}
+
+impl GetAttr for PyWeakCallableProxy {
+    // TODO: callbacks
+    fn getattro(zelf: &Py<Self>, name: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
+        let obj = zelf.try_upgrade(vm)?;
+        obj.get_attr(name, vm)
+    }
+}
+
+impl SetAttr for PyWeakCallableProxy {
+    fn setattro(
+        zelf: &Py<Self>,
+        attr_name: &Py<PyStr>,
+        value: PySetterValue,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let obj = zelf.try_upgrade(vm)?;
+        obj.call_set_attr(vm, attr_name, value)
+    }
+}
+
+impl Comparable for PyWeakCallableProxy {
+    fn cmp(
+        zelf: &Py<Self>,
+        other: &PyObject,
+        op: PyComparisonOp,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyComparisonValue> {
+        let obj = zelf.try_upgrade(vm)?;
+        Ok(PyComparisonValue::Implemented(
+            obj.rich_compare_bool(other, op, vm)?,
+        ))
+    }
+}
+
+impl AsSequence for PyWeakCallableProxy {
+    fn as_sequence() -> &'static PySequenceMethods {
+        static AS_SEQUENCE: LazyLock<PySequenceMethods> = LazyLock::new(|| PySequenceMethods {
+            length: atomic_func!(|seq, vm| PyWeakCallableProxy::sequence_downcast(seq).len(vm)),
+            contains: atomic_func!(|seq, needle, vm| {
+                PyWeakCallableProxy::sequence_downcast(seq).__contains__(needle.to_owned(), vm)
+            }),
+            ..PySequenceMethods::NOT_IMPLEMENTED
+        });
+        &AS_SEQUENCE
+    }
+}
+
+impl AsMapping for PyWeakCallableProxy {
+    fn as_mapping() -> &'static PyMappingMethods {
+        static AS_MAPPING: PyMappingMethods = PyMappingMethods {
+            length: atomic_func!(
+                |mapping, vm| PyWeakCallableProxy::mapping_downcast(mapping).len(vm)
+            ),
+            subscript: atomic_func!(|mapping, needle, vm| {
+                PyWeakCallableProxy::mapping_downcast(mapping).getitem(needle.to_owned(), vm)
+            }),
+            ass_subscript: atomic_func!(|mapping, needle, value, vm| {
+                let zelf = PyWeakCallableProxy::mapping_downcast(mapping);
+                if let Some(value) = value {
+                    zelf.setitem(needle.to_owned(), value, vm)
+                } else {
+                    zelf.delitem(needle.to_owned(), vm)
+                }
+            }),
+        };
+        &AS_MAPPING
+    }
+}
+
+impl Representable for PyWeakCallableProxy {
+    #[inline]
+    fn repr(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyStrRef> {
+        zelf.try_upgrade(vm)?.repr(vm)
+    }
+
+    #[cold]
+    fn repr_str(_zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<String> {
+        unreachable!("use repr instead")
+    }
+}
+
+impl Hashable for PyWeakCallableProxy {
     fn hash(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyHash> {
         zelf.try_upgrade(vm)?.hash(vm)
     }
 }
+
+impl Callable for PyWeakCallableProxy {
+    type Args = FuncArgs;
+
+    fn call(zelf: &Py<Self>, args: Self::Args, vm: &VirtualMachine) -> PyResult {
+        let obj = zelf.try_upgrade(vm)?;
+        obj.call(args, vm)
+    }
+}
+
+pub fn init(context: &Context) {
+    PyWeakProxy::extend_class(context, context.types.weakproxy_type);
+    PyWeakCallableProxy::extend_class(context, context.types.weakcallableproxy_type);
+}
@@ -352,6 +352,104 @@ impl CodecsRegistry {
     }
 }
 
+/// Looks for a PEP 263 encoding cookie -- `# -*- coding: <name> -*-`, or
+/// the bare `# coding: <name>` form CPython also accepts -- on either of
+/// the first two lines of `source`. Only scans lines that decode as
+/// UTF-8 on their own, since a cookie is always plain ASCII; a raw line
+/// in the file's real (still-unknown) encoding just doesn't match and is
+/// skipped rather than aborting the whole scan.
+pub fn find_coding_cookie(source: &[u8]) -> Option<String> {
+    for line in source.split(|&b| b == b'\n').take(2) {
+        let Ok(line) = std::str::from_utf8(line) else {
+            continue;
+        };
+        let trimmed = line.trim_start();
+        if !trimmed.starts_with('#') {
+            continue;
+        }
+        let Some(pos) = trimmed.find("coding") else {
+            continue;
+        };
+        let rest = &trimmed[pos + "coding".len()..];
+        let Some(rest) = rest.strip_prefix(':').or_else(|| rest.strip_prefix('=')) else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'))
+            .unwrap_or(rest.len());
+        if end > 0 {
+            return Some(rest[..end].to_ascii_lowercase());
+        }
+    }
+    None
+}
+
+/// Decodes raw source bytes honoring the same rules CPython does: a UTF-8
+/// BOM forces UTF-8 (and a conflicting coding cookie is a `SyntaxError`), a
+/// PEP 263 coding cookie picks the codec otherwise, and plain UTF-8 is the
+/// default with neither present. Shared by `eval`/`compile()` on bytes
+/// source and by script/module file loading; `filename`, when given, is
+/// folded into the `SyntaxError` message the way CPython includes the file
+/// name in its own encoding-declaration errors.
+pub fn decode_source_bytes(
+    source: &[u8],
+    filename: Option<&str>,
+    vm: &VirtualMachine,
+) -> PyResult<String> {
+    const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+    let (has_bom, body) = match source.strip_prefix(UTF8_BOM) {
+        Some(rest) => (true, rest),
+        None => (false, source),
+    };
+
+    let for_file = || match filename {
+        Some(filename) => format!(" for {filename:?}"),
+        None => String::new(),
+    };
+
+    let cookie = find_coding_cookie(body);
+    let is_utf8_alias = |name: &str| matches!(name, "utf-8" | "utf8" | "utf_8" | "u8" | "cp65001");
+
+    let encoding = match &cookie {
+        Some(cookie) => {
+            if has_bom && !is_utf8_alias(cookie) {
+                return Err(vm.new_exception_msg(
+                    vm.ctx.exceptions.syntax_error.to_owned(),
+                    format!("encoding problem: utf-8{}", for_file()),
+                ));
+            }
+            cookie.as_str()
+        }
+        None => "utf-8",
+    };
+
+    let bytes = vm.ctx.new_bytes(body.to_vec());
+    let decoded = vm
+        .state
+        .codec_registry
+        .decode_text(bytes.into(), encoding, None, vm)
+        .map_err(|err| {
+            let msg = err
+                .as_object()
+                .str(vm)
+                .map(|s| s.as_str().to_owned())
+                .unwrap_or_default();
+            if err.fast_isinstance(vm.ctx.exceptions.lookup_error) {
+                vm.new_exception_msg(
+                    vm.ctx.exceptions.syntax_error.to_owned(),
+                    format!("unknown encoding{}: {encoding}", for_file()),
+                )
+            } else {
+                vm.new_exception_msg(
+                    vm.ctx.exceptions.syntax_error.to_owned(),
+                    format!("(unicode error) {msg}"),
+                )
+            }
+        })?;
+    Ok(decoded.as_str().to_owned())
+}
+
 fn normalize_encoding_name(encoding: &str) -> Cow<'_, str> {
     if let Some(i) = encoding.find(|c: char| c == ' ' || c.is_ascii_uppercase()) {
         let mut out = encoding.as_bytes().to_owned();
@@ -1,4 +1,8 @@
-use crate::{PyObjectRef, PyResult, VirtualMachine, builtins::PyBaseExceptionRef};
+use crate::{
+    PyObjectRef, PyResult, VirtualMachine,
+    builtins::{PyBaseExceptionRef, PySet},
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 /// Implemented by any type that can be returned from a built-in Python function.
 ///
@@ -29,3 +33,74 @@ where
         self.to_pyexception(vm)
     }
 }
+
+/// Builds a Python `dict`. `ToPyObject` is infallible, so this relies on
+/// `K::to_pyobject` producing a hashable key for every `K` it's used with --
+/// true for the primitive types (`String`, `i64`, ...) this blanket impl is
+/// meant for. A `K` that can produce an unhashable Python object isn't a
+/// realistic use of this impl and will panic instead of silently dropping
+/// the entry.
+impl<K, V> ToPyObject for HashMap<K, V>
+where
+    K: ToPyObject,
+    V: ToPyObject,
+{
+    fn to_pyobject(self, vm: &VirtualMachine) -> PyObjectRef {
+        let dict = vm.ctx.new_dict();
+        for (key, value) in self {
+            dict.set_item(&*key.to_pyobject(vm), value.to_pyobject(vm), vm)
+                .expect("HashMap key must convert to a hashable Python object");
+        }
+        dict.into()
+    }
+}
+
+/// See [`ToPyObject for HashMap`](ToPyObject) -- same dict construction,
+/// just iterating a `BTreeMap` instead.
+impl<K, V> ToPyObject for BTreeMap<K, V>
+where
+    K: ToPyObject,
+    V: ToPyObject,
+{
+    fn to_pyobject(self, vm: &VirtualMachine) -> PyObjectRef {
+        let dict = vm.ctx.new_dict();
+        for (key, value) in self {
+            dict.set_item(&*key.to_pyobject(vm), value.to_pyobject(vm), vm)
+                .expect("BTreeMap key must convert to a hashable Python object");
+        }
+        dict.into()
+    }
+}
+
+/// Builds a Python `set`. Same hashability caveat as
+/// [`ToPyObject for HashMap`](ToPyObject): realistic `T`s always convert to
+/// hashable objects, so a hash failure here panics rather than propagating.
+impl<T> ToPyObject for HashSet<T>
+where
+    T: ToPyObject,
+{
+    fn to_pyobject(self, vm: &VirtualMachine) -> PyObjectRef {
+        let set = PySet::new_ref(&vm.ctx);
+        for item in self {
+            set.add(item.to_pyobject(vm), vm)
+                .expect("HashSet element must convert to a hashable Python object");
+        }
+        set.into()
+    }
+}
+
+/// See [`ToPyObject for HashSet`](ToPyObject) -- same set construction,
+/// just iterating a `BTreeSet` instead.
+impl<T> ToPyObject for BTreeSet<T>
+where
+    T: ToPyObject,
+{
+    fn to_pyobject(self, vm: &VirtualMachine) -> PyObjectRef {
+        let set = PySet::new_ref(&vm.ctx);
+        for item in self {
+            set.add(item.to_pyobject(vm), vm)
+                .expect("BTreeSet element must convert to a hashable Python object");
+        }
+        set.into()
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
     Py, VirtualMachine,
     builtins::PyFloat,
+    function::ArgMapping,
     object::{AsObject, PyObject, PyObjectRef, PyPayload, PyRef, PyResult},
 };
 use malachite_bigint::Sign;
 use num_traits::ToPrimitive;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
 
 /// Implemented by any type that can be created from a Python object.
 ///
@@ -122,6 +125,96 @@ impl<'a, T: PyPayload> TryFromBorrowedObject<'a> for &'a Py<T> {
     }
 }
 
+/// Accepts any object satisfying the mapping protocol (an exact `dict`, or
+/// anything with `keys()` + `__getitem__`), not just `dict` itself -- the
+/// same duck-typed acceptance `ArgMapping` already gives embedders.
+impl<K, V> TryFromObject for HashMap<K, V>
+where
+    K: TryFromObject + Eq + Hash,
+    V: TryFromObject,
+{
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        let mapping = ArgMapping::try_from_object(vm, obj)?;
+        let mut map = Self::new();
+        for item in mapping.iter_items(vm)? {
+            let (key, value) = item?;
+            let key = K::try_from_object(vm, key)?;
+            let value = V::try_from_object(vm, value)?;
+            if map.insert(key, value).is_some() {
+                return Err(vm.new_value_error(
+                    "duplicate keys after conversion to a Rust map (two distinct Python keys \
+                     converted to the same Rust value)",
+                ));
+            }
+        }
+        Ok(map)
+    }
+}
+
+/// See [`TryFromObject for HashMap`](TryFromObject) -- same mapping-protocol
+/// acceptance and duplicate-key rejection, ordered by `K`'s `Ord` impl
+/// instead of hashed.
+impl<K, V> TryFromObject for BTreeMap<K, V>
+where
+    K: TryFromObject + Ord,
+    V: TryFromObject,
+{
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        let mapping = ArgMapping::try_from_object(vm, obj)?;
+        let mut map = Self::new();
+        for item in mapping.iter_items(vm)? {
+            let (key, value) = item?;
+            let key = K::try_from_object(vm, key)?;
+            let value = V::try_from_object(vm, value)?;
+            if map.insert(key, value).is_some() {
+                return Err(vm.new_value_error(
+                    "duplicate keys after conversion to a Rust map (two distinct Python keys \
+                     converted to the same Rust value)",
+                ));
+            }
+        }
+        Ok(map)
+    }
+}
+
+/// Accepts any iterable, like [`Vec<T>`]'s impl. Two elements that convert
+/// to the same Rust value are a `ValueError` rather than a silently
+/// collapsed entry -- unlike a Python `set` literal, the source iterable
+/// isn't guaranteed to have already deduplicated on the Rust-side value.
+impl<'a, T: 'a + TryFromObject + Eq + Hash> TryFromBorrowedObject<'a> for HashSet<T> {
+    fn try_from_borrowed_object(vm: &VirtualMachine, value: &'a PyObject) -> PyResult<Self> {
+        let elements = vm.extract_elements_with(value, |obj| T::try_from_object(vm, obj))?;
+        let mut set = Self::with_capacity(elements.len());
+        for element in elements {
+            if !set.insert(element) {
+                return Err(vm.new_value_error(
+                    "duplicate values after conversion to a Rust set (two distinct Python \
+                     values converted to the same Rust value)",
+                ));
+            }
+        }
+        Ok(set)
+    }
+}
+
+/// See [`TryFromBorrowedObject for HashSet`](TryFromBorrowedObject) -- same
+/// iterable acceptance and duplicate rejection, ordered by `T`'s `Ord` impl.
+impl<'a, T: 'a + TryFromObject + Ord> TryFromBorrowedObject<'a> for BTreeSet<T> {
+    fn try_from_borrowed_object(vm: &VirtualMachine, value: &'a PyObject) -> PyResult<Self> {
+        let elements = vm.extract_elements_with(value, |obj| T::try_from_object(vm, obj))?;
+        let mut set = Self::new();
+        for element in elements {
+            if !set.insert(element) {
+                return Err(vm.new_value_error(
+                    "duplicate values after conversion to a Rust set (two distinct Python \
+                     values converted to the same Rust value)",
+                ));
+            }
+        }
+        Ok(set)
+    }
+}
+
 impl TryFromObject for std::time::Duration {
     fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
         if let Some(float) = obj.downcast_ref::<PyFloat>() {
@@ -149,3 +242,80 @@ impl TryFromObject for std::time::Duration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Interpreter, convert::ToPyObject};
+
+    #[test]
+    fn test_hashmap_roundtrip_nested() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let mut original: HashMap<String, Vec<i64>> = HashMap::new();
+            original.insert("a".to_owned(), vec![1, 2, 3]);
+            original.insert("b".to_owned(), vec![]);
+
+            let obj = original.clone().to_pyobject(vm);
+            let roundtripped: HashMap<String, Vec<i64>> = obj
+                .try_into_value(vm)
+                .expect("conversion back should succeed");
+            assert_eq!(original, roundtripped);
+        })
+    }
+
+    #[test]
+    fn test_btreemap_roundtrip() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let mut original: BTreeMap<String, i64> = BTreeMap::new();
+            original.insert("x".to_owned(), 1);
+            original.insert("y".to_owned(), 2);
+
+            let obj = original.clone().to_pyobject(vm);
+            let roundtripped: BTreeMap<String, i64> = obj
+                .try_into_value(vm)
+                .expect("conversion back should succeed");
+            assert_eq!(original, roundtripped);
+        })
+    }
+
+    #[test]
+    fn test_hashset_roundtrip() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let original: HashSet<i64> = [1, 2, 3].into_iter().collect();
+
+            let obj = original.clone().to_pyobject(vm);
+            let roundtripped: HashSet<i64> = obj
+                .try_into_value(vm)
+                .expect("conversion back should succeed");
+            assert_eq!(original, roundtripped);
+        })
+    }
+
+    #[test]
+    fn test_hashset_duplicate_after_conversion_is_value_error() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            // Two Python ints with the same value convert to the same Rust
+            // i64 -- that must be rejected, not silently deduplicated.
+            let list = vm
+                .ctx
+                .new_list(vec![vm.new_pyobj(1_i64), vm.new_pyobj(1_i64)]);
+            let obj: PyObjectRef = list.into();
+            let result: PyResult<HashSet<i64>> = obj.try_into_value(vm);
+            let err = result.expect_err("duplicate elements should be rejected");
+            assert!(err.fast_isinstance(vm.ctx.exceptions.value_error));
+        })
+    }
+
+    #[test]
+    fn test_btreeset_roundtrip() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let original: BTreeSet<i64> = [3, 1, 2].into_iter().collect();
+
+            let obj = original.clone().to_pyobject(vm);
+            let roundtripped: BTreeSet<i64> = obj
+                .try_into_value(vm)
+                .expect("conversion back should succeed");
+            assert_eq!(original, roundtripped);
+        })
+    }
+}
@@ -63,8 +63,9 @@ impl VirtualMachine {
                 let _ = self.write_exception(&mut py_io::IoWriter(io::stderr()), exc);
             }
         };
+        let (exc_type, exc_val, exc_tb) = vm.split_exception(exc.clone());
+        vm.set_last_exception(exc_type.clone(), exc_val.clone(), exc_tb.clone());
         if let Ok(excepthook) = vm.sys_module.get_attr("excepthook", vm) {
-            let (exc_type, exc_val, exc_tb) = vm.split_exception(exc.clone());
             if let Err(eh_exc) = excepthook.call((exc_type, exc_val, exc_tb), vm) {
                 write_fallback(&eh_exc, "Error in sys.excepthook:");
                 write_fallback(&exc, "Original exception was:");
@@ -74,6 +75,24 @@ impl VirtualMachine {
         }
     }
 
+    /// Record the most recently displayed exception on `sys.last_type` /
+    /// `sys.last_value` / `sys.last_traceback` (and, since 3.12,
+    /// `sys.last_exc`), the same bookkeeping CPython's `PyErr_PrintEx` does
+    /// before invoking `sys.excepthook`. `pdb.pm()` and
+    /// `traceback.print_last()` read these back after the fact.
+    pub fn set_last_exception(
+        &self,
+        exc_type: PyObjectRef,
+        exc_val: PyObjectRef,
+        exc_tb: PyObjectRef,
+    ) {
+        let vm = self;
+        let _ = vm.sys_module.set_attr("last_type", exc_type, vm);
+        let _ = vm.sys_module.set_attr("last_value", exc_val.clone(), vm);
+        let _ = vm.sys_module.set_attr("last_traceback", exc_tb, vm);
+        let _ = vm.sys_module.set_attr("last_exc", exc_val, vm);
+    }
+
     pub fn write_exception<W: Write>(
         &self,
         output: &mut W,
@@ -13,8 +13,8 @@ use crate::{
     convert::{IntoObject, ToPyResult},
     coroutine::Coro,
     exceptions::ExceptionCtor,
-    function::{ArgMapping, Either, FuncArgs},
-    protocol::{PyIter, PyIterReturn},
+    function::{ArgAsyncIterable, ArgAsyncIterator, ArgAwaitable, ArgMapping, Either, FuncArgs},
+    protocol::{PyIter, PyIterReturn, TraceEvent},
     scope::Scope,
     stdlib::{builtins, typing},
     vm::{Context, PyMethod},
@@ -22,7 +22,7 @@ use crate::{
 use indexmap::IndexMap;
 use itertools::Itertools;
 use rustpython_common::wtf8::Wtf8Buf;
-use rustpython_compiler_core::SourceLocation;
+use rustpython_compiler_core::{OneIndexed, SourceLocation};
 #[cfg(feature = "threading")]
 use std::sync::atomic;
 use std::{fmt, iter::zip};
@@ -87,6 +87,10 @@ struct FrameState {
     /// index of last instruction ran
     #[cfg(feature = "threading")]
     lasti: u32,
+    /// source line the trace function was last notified about, so a `line`
+    /// event only fires when execution actually moves to a different line
+    /// (including moving back to an earlier one, e.g. a loop body).
+    last_traced_line: Option<OneIndexed>,
 }
 
 #[cfg(feature = "threading")]
@@ -116,6 +120,12 @@ pub struct Frame {
     // member
     pub trace_lines: PyMutex<bool>,
     pub temporary_refs: PyMutex<Vec<PyObjectRef>>,
+
+    /// Names stashed through `f_locals` that don't correspond to one of
+    /// this frame's fast locals/cells/frees (PEP 667's "extra locals").
+    /// Lives on the frame itself, not the proxy object, so that separate
+    /// `frame.f_locals` accesses see each other's writes.
+    pub(crate) extra_locals: PyMutex<Option<PyDictRef>>,
 }
 
 impl PyPayload for Frame {
@@ -153,6 +163,7 @@ impl Frame {
             blocks: Vec::new(),
             #[cfg(feature = "threading")]
             lasti: 0,
+            last_traced_line: None,
         };
 
         Self {
@@ -168,6 +179,7 @@ impl Frame {
             trace: PyMutex::new(vm.ctx.none()),
             trace_lines: PyMutex::new(true),
             temporary_refs: PyMutex::new(vec![]),
+            extra_locals: PyMutex::new(None),
         }
     }
 
@@ -187,8 +199,17 @@ impl Frame {
     }
 
     pub fn locals(&self, vm: &VirtualMachine) -> PyResult<ArgMapping> {
-        let locals = &self.locals;
         let code = &**self.code;
+        // PEP 667: in an optimized frame (a function body), `locals()` is a
+        // snapshot taken fresh each call from the fast locals/cells/frees at
+        // that moment; writes to it never feed back into the running frame.
+        // Module and class bodies have no fast locals, so their namespace
+        // *is* `self.locals` and must stay the live, shared mapping.
+        let locals = if code.flags.contains(bytecode::CodeFlags::IS_OPTIMIZED) {
+            ArgMapping::from_dict_exact(vm.ctx.new_dict())
+        } else {
+            self.locals.clone()
+        };
         let map = &code.varnames;
         let j = std::cmp::min(map.len(), code.varnames.len());
         if !code.varnames.is_empty() {
@@ -221,7 +242,7 @@ impl Frame {
                 map_to_dict(&code.freevars, &self.cells_frees[code.cellvars.len()..])?;
             }
         }
-        Ok(locals.clone())
+        Ok(locals)
     }
 }
 
@@ -352,6 +373,28 @@ impl ExecutingFrame<'_> {
         }
     }
 
+    /// Fire a `line` trace event when execution reaches a bytecode that
+    /// starts a source line different from the one last reported, honoring
+    /// `frame.f_trace_lines` and the `sys._settrace_skip_unless_breakpoint`
+    /// fast path.
+    fn trace_line(&mut self, idx: usize, vm: &VirtualMachine) -> PyResult<()> {
+        if !*self.object.trace_lines.lock() {
+            return Ok(());
+        }
+        let row = self.code.locations[idx].row;
+        if self.state.last_traced_line == Some(row) {
+            return Ok(());
+        }
+        self.state.last_traced_line = Some(row);
+        if let Some(skip) = &*vm.trace_skip_lines.borrow() {
+            let key = (self.code.source_path.as_str().to_owned(), row.get() as u32);
+            if !skip.contains(&key) {
+                return Ok(());
+            }
+        }
+        vm.trace_event_with_arg(TraceEvent::Line, vm.ctx.none())
+    }
+
     fn run(&mut self, vm: &VirtualMachine) -> PyResult<ExecutionResult> {
         flame_guard!(format!(
             "Frame::run({obj_name})",
@@ -367,6 +410,9 @@ impl ExecutingFrame<'_> {
             //     self.code.locations[idx], self.code.source_path
             // );
             self.update_lasti(|i| *i += 1);
+            if vm.use_tracing.get() {
+                self.trace_line(idx, vm)?;
+            }
             let bytecode::CodeUnit { op, arg } = instructions[idx];
             let arg = arg_state.extend(arg);
             let mut do_extend_arg = false;
@@ -398,6 +444,10 @@ impl ExecutingFrame<'_> {
 
                         vm.contextualize_exception(&exception);
 
+                        let (exc_type, exc_val, exc_tb) = vm.split_exception(exception.clone());
+                        let trace_arg = vm.ctx.new_tuple(vec![exc_type, exc_val, exc_tb]).into();
+                        vm.trace_event_with_arg(TraceEvent::Exception, trace_arg)?;
+
                         frame.unwind_blocks(vm, UnwindReason::Raising { exception })
                     }
 
@@ -1080,27 +1130,13 @@ impl ExecutingFrame<'_> {
             }
             bytecode::Instruction::GetAwaitable => {
                 let awaited_obj = self.pop_value();
-                let awaitable = if awaited_obj.downcastable::<PyCoroutine>() {
-                    awaited_obj
-                } else {
-                    let await_method = vm.get_method_or_type_error(
-                        awaited_obj.clone(),
-                        identifier!(vm, __await__),
-                        || {
-                            format!(
-                                "object {} can't be used in 'await' expression",
-                                awaited_obj.class().name(),
-                            )
-                        },
-                    )?;
-                    await_method.call((), vm)?
-                };
+                let awaitable = ArgAwaitable::try_from_object(vm, awaited_obj)?.into_future(vm)?;
                 self.push_value(awaitable);
                 Ok(None)
             }
             bytecode::Instruction::GetAIter => {
                 let aiterable = self.pop_value();
-                let aiter = vm.call_special_method(&aiterable, identifier!(vm, __aiter__), ())?;
+                let aiter = ArgAsyncIterable::try_from_object(vm, aiterable)?.aiter(vm)?;
                 self.push_value(aiter);
                 Ok(None)
             }
@@ -1108,42 +1144,28 @@ impl ExecutingFrame<'_> {
                 #[cfg(debug_assertions)] // remove when GetANext is fully implemented
                 let orig_stack_len = self.state.stack.len();
 
-                let aiter = self.top_value();
-                let awaitable = if aiter.class().is(vm.ctx.types.async_generator) {
-                    vm.call_special_method(aiter, identifier!(vm, __anext__), ())?
-                } else {
-                    if !aiter.has_attr("__anext__", vm).unwrap_or(false) {
-                        // TODO: __anext__ must be protocol
-                        let msg = format!(
-                            "'async for' requires an iterator with __anext__ method, got {:.100}",
-                            aiter.class().name()
-                        );
-                        return Err(vm.new_type_error(msg));
+                let aiter = self.top_value().to_owned();
+                let next_iter = ArgAsyncIterator::try_from_object(vm, aiter)?.anext(vm)?;
+                let next_iter_class_name = next_iter.class().name().to_string();
+
+                // _PyCoro_GetAwaitableIter in CPython
+                fn get_awaitable_iter(next_iter: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+                    let gen_is_coroutine = |_| {
+                        // TODO: cpython gen_is_coroutine
+                        true
+                    };
+                    if next_iter.class().is(vm.ctx.types.coroutine_type)
+                        || gen_is_coroutine(&next_iter)
+                    {
+                        return Ok(next_iter);
                     }
-                    let next_iter =
-                        vm.call_special_method(aiter, identifier!(vm, __anext__), ())?;
-
-                    // _PyCoro_GetAwaitableIter in CPython
-                    fn get_awaitable_iter(next_iter: &PyObject, vm: &VirtualMachine) -> PyResult {
-                        let gen_is_coroutine = |_| {
-                            // TODO: cpython gen_is_coroutine
-                            true
-                        };
-                        if next_iter.class().is(vm.ctx.types.coroutine_type)
-                            || gen_is_coroutine(next_iter)
-                        {
-                            return Ok(next_iter.to_owned());
-                        }
-                        // TODO: error handling
-                        vm.call_special_method(next_iter, identifier!(vm, __await__), ())
-                    }
-                    get_awaitable_iter(&next_iter, vm).map_err(|_| {
-                        vm.new_type_error(format!(
-                            "'async for' received an invalid object from __anext__: {:.200}",
-                            next_iter.class().name()
-                        ))
-                    })?
-                };
+                    ArgAwaitable::try_from_object(vm, next_iter)?.into_future(vm)
+                }
+                let awaitable = get_awaitable_iter(next_iter, vm).map_err(|_| {
+                    vm.new_type_error(format!(
+                        "'async for' received an invalid object from __anext__: {next_iter_class_name:.200}"
+                    ))
+                })?;
                 self.push_value(awaitable);
                 #[cfg(debug_assertions)]
                 debug_assert_eq!(orig_stack_len + 1, self.state.stack.len());
@@ -217,6 +217,8 @@ impl FuncArgs {
                 T::arity().end(),
                 given_args,
             )))
+        } else if let Some(err) = self.check_posonly_passed_as_keyword(&T::posonly_names(), vm) {
+            Err(err)
         } else if let Some(err) = self.check_kwargs_empty(vm) {
             Err(err)
         } else {
@@ -224,6 +226,33 @@ impl FuncArgs {
         }
     }
 
+    /// A positional-only parameter (`#[pyarg(positional)]` /
+    /// `#[pyarg(positional_only)]`) is never taken from `kwargs`, so if its
+    /// name is still sitting in the leftover keywords here, the caller passed
+    /// it by keyword. Report that distinctly from a genuinely unrecognized
+    /// keyword, matching CPython's
+    /// `got some positional-only arguments passed as keyword arguments` error.
+    fn check_posonly_passed_as_keyword(
+        &self,
+        posonly_names: &[&str],
+        vm: &VirtualMachine,
+    ) -> Option<PyBaseExceptionRef> {
+        let passed_as_keyword: Vec<&str> = self
+            .kwargs
+            .keys()
+            .map(String::as_str)
+            .filter(|name| posonly_names.contains(name))
+            .collect();
+        if passed_as_keyword.is_empty() {
+            None
+        } else {
+            Some(vm.new_type_error(format!(
+                "got some positional-only arguments passed as keyword arguments: '{}'",
+                passed_as_keyword.join(", ")
+            )))
+        }
+    }
+
     pub fn check_kwargs_empty(&self, vm: &VirtualMachine) -> Option<PyBaseExceptionRef> {
         self.kwargs
             .keys()
@@ -294,6 +323,13 @@ pub trait FromArgs: Sized {
         0..=0
     }
 
+    /// The names of this signature's positional-only parameters, i.e. ones
+    /// that must never be satisfied from `kwargs`. Used to tell a positional-
+    /// only parameter passed by keyword apart from a plain unrecognized one.
+    fn posonly_names() -> Vec<&'static str> {
+        Vec::new()
+    }
+
     /// Extracts this item from the next argument(s).
     fn from_args(vm: &VirtualMachine, args: &mut FuncArgs) -> Result<Self, ArgumentError>;
 }
@@ -571,6 +607,12 @@ macro_rules! tuple_from_py_func_args {
                 min..=max
             }
 
+            fn posonly_names() -> Vec<&'static str> {
+                let mut names = Vec::new();
+                $(names.extend($T::posonly_names());)+
+                names
+            }
+
             fn from_args(vm: &VirtualMachine, args: &mut FuncArgs) -> Result<Self, ArgumentError> {
                 Ok(($($T::from_args(vm, args)?,)+))
             }
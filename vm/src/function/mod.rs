@@ -22,7 +22,10 @@ pub use getset::PySetterValue;
 pub(super) use getset::{IntoPyGetterFunc, IntoPySetterFunc, PyGetterFunc, PySetterFunc};
 pub use method::{HeapMethodDef, PyMethodDef, PyMethodFlags};
 pub use number::{ArgIndex, ArgIntoBool, ArgIntoComplex, ArgIntoFloat, ArgPrimitiveIndex, ArgSize};
-pub use protocol::{ArgCallable, ArgIterable, ArgMapping, ArgSequence};
+pub use protocol::{
+    ArgAsyncIterable, ArgAsyncIterator, ArgAwaitable, ArgCallable, ArgIterable, ArgMapping,
+    ArgSequence, ArgSequenceRef,
+};
 
 use crate::{PyObject, PyResult, VirtualMachine, builtins::PyStr, convert::TryFromBorrowedObject};
 use builtin::{BorrowedParam, OwnedParam, RefParam};
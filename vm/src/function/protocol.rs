@@ -1,14 +1,20 @@
-use super::IntoFuncArgs;
+use super::{FuncArgs, IntoFuncArgs};
 use crate::{
-    AsObject, PyObject, PyObjectRef, PyPayload, PyResult, TryFromObject, VirtualMachine,
-    builtins::{PyDict, PyDictRef, iter::PySequenceIterator},
+    AsObject, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
+    builtins::{
+        PyCoroutine, PyDict, PyDictRef, PyList, PyTuple, PyTupleRef, iter::PySequenceIterator,
+    },
     convert::ToPyObject,
     identifier,
     object::{Traverse, TraverseFn},
     protocol::{PyIter, PyIterIter, PyMapping, PyMappingMethods},
     types::{AsMapping, GenericMethod},
 };
-use std::{borrow::Borrow, marker::PhantomData, ops::Deref};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
 
 #[derive(Clone, Traverse)]
 pub struct ArgCallable {
@@ -23,6 +29,56 @@ impl ArgCallable {
         let args = args.into_args(vm);
         (self.call)(&self.obj, args, vm)
     }
+
+    /// Call with a single positional argument, e.g. a predicate in
+    /// `itertools.dropwhile`/`takewhile` or a callback in `iter(callable,
+    /// sentinel)`. Goes straight to a one-element `FuncArgs` instead of
+    /// through the generic `IntoFuncArgs`/`ToPyObject` tuple machinery.
+    ///
+    /// This still allocates the backing `Vec` -- `FuncArgs::args` is a plain
+    /// `Vec<PyObjectRef>`, and the underlying call slot (`GenericMethod`)
+    /// takes an owned `FuncArgs` by value, so a truly allocation-free
+    /// vectorcall-style path would mean changing that slot's signature (and
+    /// every `Callable` impl built on it) across the whole crate. That's out
+    /// of scope for a single, safely-verifiable change here.
+    #[inline(always)]
+    pub fn invoke1(&self, arg: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let args = FuncArgs {
+            args: vec![arg],
+            kwargs: Default::default(),
+        };
+        (self.call)(&self.obj, args, vm)
+    }
+
+    /// Call with a pre-built `FuncArgs`, for callers juggling both
+    /// positional and keyword arguments together.
+    #[inline(always)]
+    pub fn call_with_args_kwargs(&self, args: FuncArgs, vm: &VirtualMachine) -> PyResult {
+        self.invoke(args, vm)
+    }
+
+    #[inline(always)]
+    pub fn as_object(&self) -> &PyObject {
+        &self.obj
+    }
+
+    #[inline(always)]
+    pub fn into_object(self) -> PyObjectRef {
+        self.obj
+    }
+
+    /// Best-effort display name for error messages: `__qualname__`, falling
+    /// back to `__name__`, then to the callable's type name.
+    pub fn name(&self, vm: &VirtualMachine) -> String {
+        for attr in [identifier!(vm, __qualname__), identifier!(vm, __name__)] {
+            if let Ok(Some(name)) = vm.get_attribute_opt(self.obj.clone(), attr) {
+                if let Ok(name) = name.str(vm) {
+                    return name.as_str().to_owned();
+                }
+            }
+        }
+        self.obj.class().name().to_string()
+    }
 }
 
 impl std::fmt::Debug for ArgCallable {
@@ -98,6 +154,37 @@ impl<T> ArgIterable<T> {
         });
         iter.into_iter(vm)
     }
+
+    /// Best-effort size estimate via `__len__`/`__length_hint__`, so callers
+    /// collecting into a `Vec` can pre-size it. Per PEP 424, an iterable that
+    /// raises while computing its hint is treated as having no hint at all.
+    pub fn length_hint(&self, vm: &VirtualMachine) -> PyResult<Option<usize>> {
+        vm.length_hint_opt(self.iterable.clone())
+    }
+
+    /// A hint beyond this is almost certainly wrong (or actively lying), so
+    /// `try_collect_with_hint` clamps to it rather than handing an
+    /// attacker-controlled size straight to `Vec::with_capacity`, which
+    /// would abort the process instead of returning an error.
+    const MAX_PREALLOCATE_HINT: usize = 1_000_000;
+
+    /// Collects the iterable into a `Vec`, reserving capacity up front from
+    /// its length hint (clamped via [`Self::MAX_PREALLOCATE_HINT`]) to avoid
+    /// repeated reallocation on large inputs.
+    pub fn try_collect_with_hint(&self, vm: &VirtualMachine) -> PyResult<Vec<T>>
+    where
+        T: TryFromObject,
+    {
+        let cap = self
+            .length_hint(vm)?
+            .unwrap_or(0)
+            .min(Self::MAX_PREALLOCATE_HINT);
+        let mut result = Vec::with_capacity(cap);
+        for item in self.iter(vm)? {
+            result.push(item?);
+        }
+        Ok(result)
+    }
 }
 
 impl<T> TryFromObject for ArgIterable<T>
@@ -121,6 +208,102 @@ where
     }
 }
 
+/// An async-iterable Python object: one that implements `__aiter__`.
+///
+/// The async counterpart to [`ArgIterable`]. There's no dedicated type slot
+/// for `__aiter__` the way there is for `__iter__`, so validation here is a
+/// plain attribute lookup, same as `async for`'s bytecode handler.
+#[derive(Clone, Traverse)]
+pub struct ArgAsyncIterable {
+    obj: PyObjectRef,
+}
+
+impl ArgAsyncIterable {
+    /// Calls `__aiter__`, returning the resulting async iterator.
+    pub fn aiter(&self, vm: &VirtualMachine) -> PyResult {
+        vm.call_special_method(&self.obj, identifier!(vm, __aiter__), ())
+    }
+
+    #[inline(always)]
+    pub fn into_object(self) -> PyObjectRef {
+        self.obj
+    }
+}
+
+impl TryFromObject for ArgAsyncIterable {
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        if !obj.class().has_attr(identifier!(vm, __aiter__)) {
+            return Err(vm.new_type_error(format!(
+                "'{}' object is not an async iterable",
+                obj.class().name()
+            )));
+        }
+        Ok(Self { obj })
+    }
+}
+
+/// An async-iterator Python object: one that implements `__anext__`.
+#[derive(Clone, Traverse)]
+pub struct ArgAsyncIterator {
+    obj: PyObjectRef,
+}
+
+impl ArgAsyncIterator {
+    /// Calls `__anext__`, returning the awaitable it produces.
+    pub fn anext(&self, vm: &VirtualMachine) -> PyResult {
+        vm.call_special_method(&self.obj, identifier!(vm, __anext__), ())
+    }
+
+    #[inline(always)]
+    pub fn into_object(self) -> PyObjectRef {
+        self.obj
+    }
+}
+
+impl TryFromObject for ArgAsyncIterator {
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        if !obj.class().has_attr(identifier!(vm, __anext__)) {
+            return Err(vm.new_type_error(format!(
+                "'{}' object is not an async iterator",
+                obj.class().name()
+            )));
+        }
+        Ok(Self { obj })
+    }
+}
+
+/// An awaitable Python object: a coroutine, or anything implementing
+/// `__await__`.
+#[derive(Clone, Traverse)]
+pub struct ArgAwaitable {
+    obj: PyObjectRef,
+}
+
+impl ArgAwaitable {
+    /// The iterator driving this awaitable: the coroutine itself if it is
+    /// one, otherwise whatever `__await__` returns.
+    pub fn into_future(self, vm: &VirtualMachine) -> PyResult {
+        if self.obj.downcastable::<PyCoroutine>() {
+            Ok(self.obj)
+        } else {
+            vm.call_special_method(&self.obj, identifier!(vm, __await__), ())
+        }
+    }
+}
+
+impl TryFromObject for ArgAwaitable {
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        if obj.downcastable::<PyCoroutine>() || obj.class().has_attr(identifier!(vm, __await__)) {
+            Ok(Self { obj })
+        } else {
+            Err(vm.new_type_error(format!(
+                "object {} can't be used in 'await' expression",
+                obj.class().name()
+            )))
+        }
+    }
+}
+
 #[derive(Debug, Clone, Traverse)]
 pub struct ArgMapping {
     obj: PyObjectRef,
@@ -149,6 +332,47 @@ impl ArgMapping {
             methods: self.methods,
         }
     }
+
+    /// `keys()`: the mapping's `PyDict` fast path if it's an exact dict,
+    /// otherwise whatever its `keys()` method returns.
+    #[inline]
+    pub fn keys(&self, vm: &VirtualMachine) -> PyResult {
+        self.mapping().keys(vm)
+    }
+
+    /// `values()`, following the same dict-fast-path/method fallback as
+    /// [`Self::keys`].
+    #[inline]
+    pub fn values(&self, vm: &VirtualMachine) -> PyResult {
+        self.mapping().values(vm)
+    }
+
+    /// `items()`, following the same dict-fast-path/method fallback as
+    /// [`Self::keys`].
+    #[inline]
+    pub fn items(&self, vm: &VirtualMachine) -> PyResult {
+        self.mapping().items(vm)
+    }
+
+    /// A Rust iterator over `(key, value)` pairs, for callers (`**kwargs`
+    /// unpacking, `dict.update`, `exec`'s locals handling) that just want to
+    /// walk the mapping instead of building a Python `items()` view. Doesn't
+    /// require an `items()` method on the mapping: like CPython's
+    /// `PyMapping_Keys`-based consumers, it drives `keys()` and looks up
+    /// each value with `__getitem__`, which also covers dict-like objects
+    /// that only implement `keys` + `__getitem__`.
+    pub fn iter_items<'a>(
+        &'a self,
+        vm: &'a VirtualMachine,
+    ) -> PyResult<impl Iterator<Item = PyResult<(PyObjectRef, PyObjectRef)>> + 'a> {
+        let keys = self.keys(vm)?.get_iter(vm)?.into_iter::<PyObjectRef>(vm)?;
+        let obj = &self.obj;
+        Ok(keys.map(move |key| {
+            let key = key?;
+            let value = obj.get_item(&*key, vm)?;
+            Ok((key, value))
+        }))
+    }
 }
 
 impl Borrow<PyObject> for ArgMapping {
@@ -214,6 +438,30 @@ impl<T> ArgSequence<T> {
     pub fn as_slice(&self) -> &[T] {
         &self.0
     }
+    #[inline(always)]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: TryFromObject> Extend<T> for ArgSequence<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> FromIterator<T> for ArgSequence<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(Vec::from_iter(iter))
+    }
 }
 
 impl<T> std::ops::Deref for ArgSequence<T> {
@@ -244,3 +492,78 @@ impl<T: TryFromObject> TryFromObject for ArgSequence<T> {
         obj.try_to_value(vm).map(Self)
     }
 }
+
+/// Like `ArgSequence<PyObjectRef>`, but avoids copying every element into a
+/// fresh `Vec` when the argument is exactly a `list` or `tuple` -- the
+/// common case for functions like `min`/`max` with a single sequence
+/// argument. Anything else (a subclass, or some other iterable) falls back
+/// to collecting into an owned `Vec`, same as `ArgSequence`.
+pub enum ArgSequenceRef {
+    List(PyRef<PyList>),
+    Tuple(PyTupleRef),
+    Vec(Vec<PyObjectRef>),
+}
+
+impl ArgSequenceRef {
+    /// Runs `f` against this sequence's elements as a plain slice.
+    ///
+    /// A `PyTuple` or a plain `Vec` is immutable for the caller's purposes,
+    /// so `f` just borrows it directly. A `PyList` is different: `f` may
+    /// call back into arbitrary Python (e.g. a `key` function), and if that
+    /// callback reentrantly mutated the very list we're borrowing, holding
+    /// its read lock across the call would deadlock the moment it tried to
+    /// append to itself. So for `List`, we borrow `PyList::sort`'s trick
+    /// instead: detach the list's backing storage before calling `f` (a
+    /// move, not a clone) and restore it afterwards, releasing the list's
+    /// lock for the whole time `f` runs. A `key` function that mutates the
+    /// list mid-call sees it as empty, same as during `sort()`, and any
+    /// such mutation is discarded and reported the same way `sort()` does.
+    pub fn with_elements<R>(
+        &self,
+        vm: &VirtualMachine,
+        f: impl FnOnce(&[PyObjectRef]) -> PyResult<R>,
+    ) -> PyResult<R> {
+        match self {
+            Self::List(list) => {
+                let mut elements = std::mem::take(list.borrow_vec_mut().deref_mut());
+                let res = f(&elements);
+                std::mem::swap(list.borrow_vec_mut().deref_mut(), &mut elements);
+                let res = res?;
+                if !elements.is_empty() {
+                    return Err(vm.new_value_error("list modified during iteration"));
+                }
+                Ok(res)
+            }
+            Self::Tuple(tuple) => f(tuple.as_slice()),
+            Self::Vec(vec) => f(vec.as_slice()),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::List(list) => list.borrow_vec().len(),
+            Self::Tuple(tuple) => tuple.as_slice().len(),
+            Self::Vec(vec) => vec.len(),
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl TryFromObject for ArgSequenceRef {
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        let obj = match obj.downcast_exact::<PyList>(vm) {
+            Ok(list) => return Ok(Self::List(list.into_pyref())),
+            Err(obj) => obj,
+        };
+        let obj = match obj.downcast_exact::<PyTuple>(vm) {
+            Ok(tuple) => return Ok(Self::Tuple(tuple.into_pyref())),
+            Err(obj) => obj,
+        };
+        Vec::<PyObjectRef>::try_from_object(vm, obj).map(Self::Vec)
+    }
+}
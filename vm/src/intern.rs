@@ -1,8 +1,9 @@
 use rustpython_common::wtf8::{Wtf8, Wtf8Buf};
 
 use crate::{
-    AsObject, Py, PyExact, PyObject, PyObjectRef, PyPayload, PyRef, PyRefExact, VirtualMachine,
-    builtins::{PyStr, PyStrInterned, PyTypeRef},
+    AsObject, Context, Py, PyExact, PyObject, PyObjectRef, PyPayload, PyRef, PyRefExact,
+    VirtualMachine,
+    builtins::{PyStr, PyStrInterned, PyTypeRef, PyWeak},
     common::lock::PyRwLock,
     convert::ToPyObject,
 };
@@ -80,6 +81,50 @@ impl StringPool {
     }
 }
 
+/// A weak, content-addressed cache of `str` objects, used to share storage
+/// for constants that are worth deduplicating (e.g. long string literals
+/// repeated across independently-compiled `co_consts`) without interning
+/// them forever the way [`StringPool`] does -- an entry is dropped as soon
+/// as nothing else in the program still references the string.
+#[derive(Debug, Default)]
+pub struct WeakValueCache {
+    inner: PyRwLock<std::collections::HashMap<Wtf8Buf, PyRef<PyWeak>, ahash::RandomState>>,
+}
+
+impl WeakValueCache {
+    /// Return a `str` object equal to `value`, reusing a still-live one from
+    /// the cache when possible instead of allocating a new one.
+    pub fn get_or_insert_str(&self, value: &Wtf8, ctx: &Context) -> PyObjectRef {
+        if let Some(obj) = Self::upgrade(&self.inner.read(), value) {
+            return obj;
+        }
+
+        #[cold]
+        fn miss(zelf: &WeakValueCache, value: &Wtf8, ctx: &Context) -> PyObjectRef {
+            let mut inner = zelf.inner.write();
+            // someone may have raced us and inserted a value while we didn't hold the lock
+            if let Some(obj) = WeakValueCache::upgrade(&inner, value) {
+                return obj;
+            }
+            let obj: PyObjectRef = ctx.new_str(value.to_owned()).into();
+            if let Some(weak) =
+                obj.downgrade_with_weakref_typ_opt(None, ctx.types.weakref_type.to_owned())
+            {
+                inner.insert(value.to_owned(), weak);
+            }
+            obj
+        }
+        miss(self, value, ctx)
+    }
+
+    fn upgrade(
+        inner: &std::collections::HashMap<Wtf8Buf, PyRef<PyWeak>, ahash::RandomState>,
+        value: &Wtf8,
+    ) -> Option<PyObjectRef> {
+        inner.get(value).and_then(|weak| weak.upgrade())
+    }
+}
+
 #[derive(Debug, Clone)]
 #[repr(transparent)]
 pub struct CachedPyStrRef {
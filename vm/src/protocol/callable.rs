@@ -1,4 +1,6 @@
 use crate::{
+    PyObjectRef,
+    builtins::{PyBoundMethod, PyFunction},
     function::{FuncArgs, IntoFuncArgs},
     types::GenericMethod,
     {AsObject, PyObject, PyResult, VirtualMachine},
@@ -48,17 +50,79 @@ impl<'a> PyCallable<'a> {
 
     pub fn invoke(&self, args: impl IntoFuncArgs, vm: &VirtualMachine) -> PyResult {
         let args = args.into_args(vm);
-        vm.trace_event(TraceEvent::Call)?;
-        let result = (self.call)(self.obj, args, vm);
-        vm.trace_event(TraceEvent::Return)?;
-        result
+        if !vm.use_tracing.get() {
+            return (self.call)(self.obj, args, vm);
+        }
+        if is_python_call(self.obj) {
+            // Pass the callee itself as `arg`, the same way the c_call branch
+            // below does. The callee's frame isn't pushed until inside
+            // `(self.call)(...)`, so `arg` is the only way a hook fired here
+            // can identify what's being entered without waiting for a `line`
+            // event from inside the new frame.
+            vm.trace_event_with_arg(TraceEvent::Call, self.obj.to_owned())?;
+            let result = (self.call)(self.obj, args, vm);
+            vm.trace_event(TraceEvent::Return)?;
+            result
+        } else {
+            // A call into a builtin/native callable: sys.settrace's trace
+            // function never sees these, only sys.setprofile's, as
+            // "c_call"/"c_return"/"c_exception".
+            vm.trace_event_with_arg(TraceEvent::CCall, self.obj.to_owned())?;
+            let result = (self.call)(self.obj, args, vm);
+            let event = if result.is_ok() {
+                TraceEvent::CReturn
+            } else {
+                TraceEvent::CException
+            };
+            vm.trace_event_with_arg(event, self.obj.to_owned())?;
+            result
+        }
+    }
+}
+
+/// Whether invoking `obj` runs interpreted Python bytecode (and so pushes its
+/// own frame) rather than jumping straight into native code. Bound methods
+/// are unwrapped since they're a transparent wrapper around the function
+/// they're bound to.
+fn is_python_call(obj: &PyObject) -> bool {
+    if obj.downcastable::<PyFunction>() {
+        return true;
     }
+    if let Some(bound) = obj.downcast_ref::<PyBoundMethod>() {
+        return is_python_call(bound.function());
+    }
+    false
 }
 
 /// Trace events for sys.settrace and sys.setprofile.
-enum TraceEvent {
+pub(crate) enum TraceEvent {
     Call,
+    Line,
     Return,
+    Exception,
+    /// A call into a builtin/native callable. Profile-only, like `CReturn`
+    /// and `CException` below -- sys.settrace's trace function never sees
+    /// these.
+    CCall,
+    CReturn,
+    CException,
+}
+
+impl TraceEvent {
+    /// `sys.settrace`'s trace function only sees Python-level call/line/
+    /// return/exception events; the c_call family is `sys.setprofile`-only.
+    const fn is_profile_only(&self) -> bool {
+        matches!(self, Self::CCall | Self::CReturn | Self::CException)
+    }
+
+    /// The flip side of [`Self::is_profile_only`]: `line` and `exception`
+    /// are only ever interesting to a line-by-line tracer, and CPython's
+    /// `sys.setprofile` function never receives them either -- a profiler
+    /// only cares about call/return timing, so skipping these keeps
+    /// per-line overhead out of the profiling path entirely.
+    const fn is_trace_only(&self) -> bool {
+        matches!(self, Self::Line | Self::Exception)
+    }
 }
 
 impl std::fmt::Display for TraceEvent {
@@ -66,7 +130,12 @@ impl std::fmt::Display for TraceEvent {
         use TraceEvent::*;
         match self {
             Call => write!(f, "call"),
+            Line => write!(f, "line"),
             Return => write!(f, "return"),
+            Exception => write!(f, "exception"),
+            CCall => write!(f, "c_call"),
+            CReturn => write!(f, "c_return"),
+            CException => write!(f, "c_exception"),
         }
     }
 }
@@ -75,16 +144,27 @@ impl VirtualMachine {
     /// Call registered trace function.
     #[inline]
     fn trace_event(&self, event: TraceEvent) -> PyResult<()> {
+        self.trace_event_with_arg(event, self.ctx.none())
+    }
+
+    /// Like [`Self::trace_event`], but passes `arg` instead of `None` as the
+    /// third argument to the trace/profile function (used for `exception`
+    /// events, which pass the `(type, value, traceback)` triple).
+    #[inline]
+    pub(crate) fn trace_event_with_arg(&self, event: TraceEvent, arg: PyObjectRef) -> PyResult<()> {
         if self.use_tracing.get() {
-            self._trace_event_inner(event)
+            self._trace_event_inner(event, arg)
         } else {
             Ok(())
         }
     }
-    fn _trace_event_inner(&self, event: TraceEvent) -> PyResult<()> {
+
+    fn _trace_event_inner(&self, event: TraceEvent, arg: PyObjectRef) -> PyResult<()> {
         let trace_func = self.trace_func.borrow().to_owned();
         let profile_func = self.profile_func.borrow().to_owned();
-        if self.is_none(&trace_func) && self.is_none(&profile_func) {
+        let wants_trace = !event.is_profile_only() && !self.is_none(&trace_func);
+        let wants_profile = !event.is_trace_only() && !self.is_none(&profile_func);
+        if !wants_trace && !wants_profile {
             return Ok(());
         }
 
@@ -94,12 +174,12 @@ impl VirtualMachine {
         }
 
         let frame = frame_ref.unwrap().as_object().to_owned();
-        let event = self.ctx.new_str(event.to_string()).into();
-        let args = vec![frame, event, self.ctx.none()];
+        let event_str = self.ctx.new_str(event.to_string()).into();
+        let args = vec![frame, event_str, arg];
 
         // temporarily disable tracing, during the call to the
         // tracing function itself.
-        if !self.is_none(&trace_func) {
+        if wants_trace {
             self.use_tracing.set(false);
             let res = trace_func.call(args.clone(), self);
             self.use_tracing.set(true);
@@ -108,7 +188,7 @@ impl VirtualMachine {
             }
         }
 
-        if !self.is_none(&profile_func) {
+        if wants_profile {
             self.use_tracing.set(false);
             let res = profile_func.call(args, self);
             self.use_tracing.set(true);
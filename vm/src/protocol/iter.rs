@@ -66,6 +66,14 @@ where
     ) -> PyResult<PyIterIter<'a, U, &'b PyObject>> {
         Ok(PyIterIter::new(vm, self.0.borrow(), None))
     }
+
+    /// Best-effort size estimate via `__len__`/`__length_hint__`, mirroring
+    /// [`crate::function::ArgIterable::length_hint`]. Per PEP 424, an
+    /// iterable that raises while computing its hint is treated as having
+    /// no hint at all.
+    pub fn length_hint(&self, vm: &VirtualMachine) -> PyResult<Option<usize>> {
+        vm.length_hint_opt(self.0.borrow().to_owned())
+    }
 }
 
 impl PyIter<PyObjectRef> {
@@ -1,5 +1,5 @@
 mod buffer;
-mod callable;
+pub(crate) mod callable;
 mod iter;
 mod mapping;
 mod number;
@@ -8,6 +8,7 @@ mod sequence;
 
 pub use buffer::{BufferDescriptor, BufferMethods, BufferResizeGuard, PyBuffer, VecBuffer};
 pub use callable::PyCallable;
+pub(crate) use callable::TraceEvent;
 pub use iter::{PyIter, PyIterIter, PyIterReturn};
 pub use mapping::{PyMapping, PyMappingMethods};
 pub use number::{
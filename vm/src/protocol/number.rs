@@ -25,9 +25,12 @@ impl PyObject {
     pub fn try_index_opt(&self, vm: &VirtualMachine) -> Option<PyResult<PyIntRef>> {
         if let Some(i) = self.downcast_ref_if_exact::<PyInt>(vm) {
             Some(Ok(i.to_owned()))
-        } else if let Some(i) = self.downcast_ref::<PyInt>() {
-            Some(Ok(vm.ctx.new_bigint(i.as_bigint())))
         } else {
+            // Don't shortcut through the raw `PyInt` payload here: an int
+            // subclass may override `__index__`, and that override has to
+            // win over the subclass's own stored value. Slot dispatch
+            // (`index`) already handles the no-override case correctly by
+            // cloning the value down to a plain `int` via `clone_exact`.
             self.to_number().index(vm)
         }
     }
@@ -114,12 +114,33 @@ where
 
 impl<T: Clone> SequenceExt<T> for [T] {}
 
+/// The over-allocation formula CPython's `list_resize()` uses: grow to
+/// roughly 9/8 the requested size plus a small constant, instead of
+/// doubling, so hot append loops see CPython-like reallocation cadence
+/// (and `__sizeof__`, which already reports real `Vec` capacity, stays
+/// close to what CPython reports too).
+fn overallocated_capacity(requested: usize) -> usize {
+    requested + (requested >> 3) + if requested < 9 { 3 } else { 6 }
+}
+
 pub trait SequenceMutExt<T: Clone>
 where
     Self: AsRef<[T]>,
 {
     fn as_vec_mut(&mut self) -> &mut Vec<T>;
 
+    /// Reserve room for `additional` more elements using CPython's growth
+    /// formula rather than Rust's default (roughly doubling) growth, so
+    /// callers appending one element at a time in a loop don't over- or
+    /// under-allocate relative to CPython.
+    fn reserve_cpython_style(&mut self, additional: usize) {
+        let vec = self.as_vec_mut();
+        let needed = vec.len() + additional;
+        if needed > vec.capacity() {
+            vec.reserve_exact(overallocated_capacity(needed) - vec.len());
+        }
+    }
+
     fn imul(&mut self, vm: &VirtualMachine, n: isize) -> PyResult<()> {
         let n = vm.check_repeat_or_overflow_error(self.as_ref().len(), n)?;
         if n == 0 {
@@ -0,0 +1,220 @@
+pub(crate) use _symtable::make_module;
+
+/// The raw, low-level counterpart to the `symtable` module -- mirrors
+/// CPython's `_symtable` extension module (normally implemented in C,
+/// with `Lib/symtable.py` as a thin pure-Python wrapper over it) closely
+/// enough that third-party code importing `_symtable` directly (some
+/// linters, and CPython's own test suite) finds what it expects.
+///
+/// `symtable` (this crate's `stdlib::symtable`) is implemented natively
+/// instead of as a wrapper over this module, so the two don't share any
+/// code; this module exists purely for compatibility with code that
+/// bypasses the public `symtable` module.
+#[pymodule]
+mod _symtable {
+    use crate::{
+        PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine, builtins::PyStrRef, compiler,
+    };
+    use rustpython_codegen::symboltable::{
+        CompilerScope, Symbol, SymbolFlags, SymbolScope, SymbolTable,
+    };
+
+    // Table types, from CPython's Include/internal/pycore_symtable.h.
+    #[pyattr]
+    const TYPE_FUNCTION: i32 = 0;
+    #[pyattr]
+    const TYPE_CLASS: i32 = 1;
+    #[pyattr]
+    const TYPE_MODULE: i32 = 2;
+
+    // Symbol flags, from the same header.
+    #[pyattr]
+    const DEF_GLOBAL: i32 = 1;
+    #[pyattr]
+    const DEF_LOCAL: i32 = 2;
+    #[pyattr]
+    const DEF_PARAM: i32 = 4;
+    #[pyattr]
+    const DEF_NONLOCAL: i32 = 8;
+    #[pyattr]
+    const DEF_FREE: i32 = 32;
+    #[pyattr]
+    const DEF_FREE_CLASS: i32 = 64;
+    #[pyattr]
+    const DEF_IMPORT: i32 = 128;
+    #[pyattr]
+    const DEF_ANNOT: i32 = 256;
+    #[pyattr]
+    const DEF_BOUND: i32 = DEF_LOCAL | DEF_PARAM | DEF_IMPORT;
+
+    // Scope, packed into the high bits of each symbol's flag word.
+    #[pyattr]
+    const SCOPE_OFF: i32 = 11;
+    #[pyattr]
+    const SCOPE_MASK: i32 = DEF_GLOBAL | DEF_LOCAL | DEF_PARAM | DEF_NONLOCAL;
+    #[pyattr]
+    const LOCAL: i32 = 1;
+    #[pyattr]
+    const GLOBAL_EXPLICIT: i32 = 2;
+    #[pyattr]
+    const GLOBAL_IMPLICIT: i32 = 3;
+    #[pyattr]
+    const FREE: i32 = 4;
+    #[pyattr]
+    const CELL: i32 = 5;
+
+    #[pyattr]
+    const USE: i32 = 16;
+
+    #[pyfunction]
+    fn symtable(
+        source: PyStrRef,
+        filename: PyStrRef,
+        mode: PyStrRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyRef<PySTEntry>> {
+        let mode = mode.as_str().parse::<compiler::Mode>().map_err(|_| {
+            vm.new_value_error("symtable() arg 3 must be 'exec', 'eval' or 'single'")
+        })?;
+        let symtable = compiler::compile_symtable(source.as_str(), mode, filename.as_str())
+            .map_err(|err| vm.new_syntax_error(&err, Some(source.as_str())))?;
+        Ok(PySTEntry { symtable }.into_ref(&vm.ctx))
+    }
+
+    const fn scope_to_raw(scope: SymbolScope) -> i32 {
+        match scope {
+            // Not a real CPython scope; a symbol RustPython hasn't
+            // classified yet shouldn't reach here in a finished table, but
+            // fall back to LOCAL rather than panicking.
+            SymbolScope::Unknown | SymbolScope::Local => LOCAL,
+            SymbolScope::GlobalExplicit => GLOBAL_EXPLICIT,
+            SymbolScope::GlobalImplicit => GLOBAL_IMPLICIT,
+            SymbolScope::Free => FREE,
+            SymbolScope::Cell => CELL,
+        }
+    }
+
+    /// Pack a symbol's scope and flags into the same single-int
+    /// representation CPython's compiler uses, well enough for
+    /// `Lib/symtable.py`'s `Symbol` accessors (`is_local()`,
+    /// `is_parameter()`, etc.) to work correctly on top of it.
+    fn symbol_to_raw(symbol: &Symbol) -> i32 {
+        let mut raw = scope_to_raw(symbol.scope) << SCOPE_OFF;
+        if symbol.scope == SymbolScope::GlobalExplicit {
+            raw |= DEF_GLOBAL;
+        }
+        if symbol.flags.contains(SymbolFlags::REFERENCED) {
+            raw |= USE;
+        }
+        if symbol.flags.contains(SymbolFlags::ASSIGNED) {
+            raw |= DEF_LOCAL;
+        }
+        if symbol.flags.contains(SymbolFlags::PARAMETER) {
+            raw |= DEF_PARAM;
+        }
+        if symbol.flags.contains(SymbolFlags::ANNOTATED) {
+            raw |= DEF_ANNOT;
+        }
+        if symbol.flags.contains(SymbolFlags::IMPORTED) {
+            raw |= DEF_IMPORT;
+        }
+        if symbol.flags.contains(SymbolFlags::NONLOCAL) {
+            raw |= DEF_NONLOCAL;
+        }
+        if symbol.flags.contains(SymbolFlags::FREE_CLASS) {
+            raw |= DEF_FREE_CLASS;
+        }
+        raw
+    }
+
+    const fn type_to_raw(typ: CompilerScope) -> i32 {
+        match typ {
+            CompilerScope::Module => TYPE_MODULE,
+            CompilerScope::Class => TYPE_CLASS,
+            // Lambdas, comprehensions and (async) functions are all
+            // TYPE_FUNCTION in CPython too. RustPython's TypeParams scope
+            // (PEP 695) has no CPython equivalent; TYPE_FUNCTION is the
+            // closest fit since it's likewise "optimized" local storage.
+            CompilerScope::Function
+            | CompilerScope::AsyncFunction
+            | CompilerScope::Lambda
+            | CompilerScope::Comprehension
+            | CompilerScope::TypeParams => TYPE_FUNCTION,
+        }
+    }
+
+    /// The raw table object CPython's `_symtable.symtable()` returns --
+    /// unlike `symtable.SymbolTable`, its `symbols` are plain ints (the
+    /// packed scope+flags word) rather than a friendly wrapper class.
+    #[pyattr]
+    #[pyclass(name = "symtable")]
+    #[derive(PyPayload)]
+    struct PySTEntry {
+        symtable: SymbolTable,
+    }
+
+    impl std::fmt::Debug for PySTEntry {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "<symtable entry {}>", self.symtable.name)
+        }
+    }
+
+    #[pyclass]
+    impl PySTEntry {
+        #[pygetset]
+        fn name(&self) -> String {
+            self.symtable.name.clone()
+        }
+
+        #[pygetset(name = "type")]
+        fn type_(&self) -> i32 {
+            type_to_raw(self.symtable.typ)
+        }
+
+        #[pygetset]
+        const fn id(&self) -> usize {
+            self.symtable.id
+        }
+
+        #[pygetset]
+        const fn lineno(&self) -> u32 {
+            self.symtable.line_number
+        }
+
+        #[pygetset]
+        const fn nested(&self) -> i32 {
+            // CPython's raw `nested` attribute is a plain int (0 or 1), not
+            // a bool -- `Lib/symtable.py` does `bool(self._table.nested)`.
+            self.symtable.is_nested as i32
+        }
+
+        #[pygetset]
+        fn symbols(&self, vm: &VirtualMachine) -> PyObjectRef {
+            let dict = vm.ctx.new_dict();
+            for (name, symbol) in &self.symtable.symbols {
+                dict.set_item(
+                    name.as_str(),
+                    vm.ctx.new_int(symbol_to_raw(symbol)).into(),
+                    vm,
+                )
+                .unwrap();
+            }
+            dict.into()
+        }
+
+        #[pygetset]
+        fn children(&self, vm: &VirtualMachine) -> Vec<PyObjectRef> {
+            self.symtable
+                .sub_tables
+                .iter()
+                .map(|t| {
+                    PySTEntry {
+                        symtable: t.clone(),
+                    }
+                    .into_ref(&vm.ctx)
+                    .into()
+                })
+                .collect()
+        }
+    }
+}
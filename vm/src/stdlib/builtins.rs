@@ -12,7 +12,8 @@ mod builtins {
     use crate::{
         AsObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
         builtins::{
-            PyByteArray, PyBytes, PyDictRef, PyStr, PyStrRef, PyTuple, PyTupleRef, PyType,
+            PyByteArray, PyBytes, PyDictRef, PyFloat, PyInt, PyStr, PyStrRef, PyTuple, PyTupleRef,
+            PyType,
             enumerate::PyReverseSequenceIterator,
             function::{PyCellRef, PyFunction},
             int::PyIntRef,
@@ -21,9 +22,9 @@ mod builtins {
         },
         common::{hash::PyHash, str::to_ascii},
         function::{
-            ArgBytesLike, ArgCallable, ArgIndex, ArgIntoBool, ArgIterable, ArgMapping,
-            ArgStrOrBytesLike, Either, FsPath, FuncArgs, KwArgs, OptionalArg, OptionalOption,
-            PosArgs,
+            ArgAsyncIterable, ArgBytesLike, ArgCallable, ArgIndex, ArgIntoBool, ArgIterable,
+            ArgMapping, ArgSequenceRef, ArgStrOrBytesLike, Either, FsPath, FuncArgs, KwArgs,
+            OptionalArg, OptionalOption, PosArgs,
         },
         protocol::{PyIter, PyIterReturn},
         py_io,
@@ -32,6 +33,7 @@ mod builtins {
         types::PyComparisonOp,
     };
     use itertools::Itertools;
+    use malachite_bigint::BigInt;
     use num_traits::{Signed, ToPrimitive};
     use rustpython_common::wtf8::CodePoint;
 
@@ -71,16 +73,29 @@ mod builtins {
         Ok(ascii)
     }
 
-    #[pyfunction]
-    fn bin(x: PyIntRef) -> String {
-        let x = x.as_bigint();
-        if x.is_negative() {
-            format!("-0b{:b}", x.abs())
+    /// Shared by `bin`/`oct`/`hex`: `number` has already gone through
+    /// `ArgIndex`, which calls `__index__` (so an int subclass overriding
+    /// it contributes the overridden value, and non-integers fail with
+    /// CPython's `'X' object cannot be interpreted as an integer`); this
+    /// just renders the resulting bigint with the requested radix prefix.
+    fn format_index_radix(
+        number: &ArgIndex,
+        prefix: &str,
+        digits: impl Fn(&BigInt) -> String,
+    ) -> String {
+        let n = number.as_bigint();
+        if n.is_negative() {
+            format!("-{prefix}{}", digits(&n.abs()))
         } else {
-            format!("0b{x:b}")
+            format!("{prefix}{}", digits(n))
         }
     }
 
+    #[pyfunction]
+    fn bin(number: ArgIndex) -> String {
+        format_index_radix(&number, "0b", |n| format!("{n:b}"))
+    }
+
     #[pyfunction]
     fn callable(obj: PyObjectRef) -> bool {
         obj.is_callable()
@@ -174,11 +189,15 @@ mod builtins {
                 use ruff_python_parser as parser;
 
                 let source = ArgStrOrBytesLike::try_from_object(vm, args.source)?;
-                let source = source.borrow_bytes();
-
-                // TODO: compiler::compile should probably get bytes
-                let source = std::str::from_utf8(&source)
-                    .map_err(|e| vm.new_unicode_decode_error(e.to_string()))?;
+                let source = match &source {
+                    // a coding cookie only applies to bytes source -- a str
+                    // argument is already decoded, same as CPython.
+                    ArgStrOrBytesLike::Buf(_) => {
+                        crate::codecs::decode_source_bytes(&source.borrow_bytes(), None, vm)?
+                    }
+                    ArgStrOrBytesLike::Str(s) => s.as_str().to_owned(),
+                };
+                let source = source.as_str();
 
                 let flags = args.flags.map_or(Ok(0), |v| v.try_to_primitive(vm))?;
 
@@ -326,7 +345,17 @@ mod builtins {
 
         // source as string
         let code = match source {
-            Either::A(either) => {
+            Either::A(ArgStrOrBytesLike::Str(s)) => {
+                if s.as_str().contains('\0') {
+                    return Err(vm.new_exception_msg(
+                        vm.ctx.exceptions.syntax_error.to_owned(),
+                        "source code string cannot contain null bytes".to_owned(),
+                    ));
+                }
+                // already decoded -- no coding cookie applies, same as CPython.
+                Ok(Either::A(vm.ctx.new_str(s.as_str().trim_start())))
+            }
+            Either::A(either @ ArgStrOrBytesLike::Buf(_)) => {
                 let source: &[u8] = &either.borrow_bytes();
                 if source.contains(&0) {
                     return Err(vm.new_exception_msg(
@@ -335,15 +364,7 @@ mod builtins {
                     ));
                 }
 
-                let source = std::str::from_utf8(source).map_err(|err| {
-                    let msg = format!(
-                        "(unicode error) 'utf-8' codec can't decode byte 0x{:x?} in position {}: invalid start byte",
-                        source[err.valid_up_to()],
-                        err.valid_up_to()
-                    );
-
-                    vm.new_exception_msg(vm.ctx.exceptions.syntax_error.to_owned(), msg)
-                })?;
+                let source = crate::codecs::decode_source_bytes(source, None, vm)?;
                 Ok(Either::A(vm.ctx.new_str(source.trim_start())))
             }
             Either::B(code) => Ok(Either::B(code)),
@@ -453,8 +474,7 @@ mod builtins {
 
     #[pyfunction]
     fn hex(number: ArgIndex) -> String {
-        let n = number.as_bigint();
-        format!("{n:#x}")
+        format_index_radix(&number, "0x", |n| format!("{n:x}"))
     }
 
     #[pyfunction]
@@ -528,8 +548,8 @@ mod builtins {
     }
 
     #[pyfunction]
-    fn aiter(iter_target: PyObjectRef, vm: &VirtualMachine) -> PyResult {
-        iter_target.get_aiter(vm)
+    fn aiter(iter_target: ArgAsyncIterable, vm: &VirtualMachine) -> PyResult {
+        iter_target.aiter(vm)
     }
 
     #[pyfunction]
@@ -542,6 +562,69 @@ mod builtins {
         vm.current_locals()
     }
 
+    /// The scalar kinds `min`/`max` know how to compare without going
+    /// through `rich_compare_bool`. Anything else (including subclasses of
+    /// these, e.g. `bool`) falls back to the generic protocol dispatch.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum FastScalarKind {
+        Int,
+        Float,
+        Str,
+    }
+
+    fn fast_scalar_kind(obj: &PyObjectRef, vm: &VirtualMachine) -> Option<FastScalarKind> {
+        if obj.class().is(vm.ctx.types.int_type) {
+            Some(FastScalarKind::Int)
+        } else if obj.class().is(vm.ctx.types.float_type) {
+            Some(FastScalarKind::Float)
+        } else if obj.class().is(vm.ctx.types.str_type) {
+            Some(FastScalarKind::Str)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `y` should replace `x` as the running best,
+    /// comparing the two directly in Rust rather than through
+    /// `rich_compare_bool`. `kind` must be the exact scalar kind of both
+    /// `x` and `y` (checked by the caller via [`fast_scalar_kind`]).
+    fn fast_scalar_wins(
+        x: &PyObjectRef,
+        y: &PyObjectRef,
+        kind: FastScalarKind,
+        op: PyComparisonOp,
+    ) -> bool {
+        match kind {
+            FastScalarKind::Int => {
+                let x = x.downcast_ref::<PyInt>().unwrap().as_bigint();
+                let y = y.downcast_ref::<PyInt>().unwrap().as_bigint();
+                match op {
+                    PyComparisonOp::Lt => y < x,
+                    PyComparisonOp::Gt => y > x,
+                    _ => unreachable!("min_or_max only ever passes Lt or Gt"),
+                }
+            }
+            FastScalarKind::Float => {
+                let x = x.downcast_ref::<PyFloat>().unwrap().to_f64();
+                let y = y.downcast_ref::<PyFloat>().unwrap().to_f64();
+                match op {
+                    PyComparisonOp::Lt => y < x,
+                    PyComparisonOp::Gt => y > x,
+                    _ => unreachable!("min_or_max only ever passes Lt or Gt"),
+                }
+            }
+            FastScalarKind::Str => {
+                let x = x.downcast_ref::<PyStr>().unwrap().as_str();
+                let y = y.downcast_ref::<PyStr>().unwrap().as_str();
+                match op {
+                    PyComparisonOp::Lt => y < x,
+                    PyComparisonOp::Gt => y > x,
+                    _ => unreachable!("min_or_max only ever passes Lt or Gt"),
+                }
+            }
+        }
+    }
+
     fn min_or_max(
         mut args: FuncArgs,
         vm: &VirtualMachine,
@@ -562,9 +645,11 @@ mod builtins {
                         "Cannot specify a default for {func_name}() with multiple positional arguments"
                     )));
                 }
-                args.args
+                ArgSequenceRef::Vec(args.args)
+            }
+            std::cmp::Ordering::Equal => {
+                ArgSequenceRef::try_from_object(vm, args.args.into_iter().next().unwrap())?
             }
-            std::cmp::Ordering::Equal => args.args[0].try_to_value(vm)?,
             std::cmp::Ordering::Less => {
                 // zero arguments means type error:
                 return Err(
@@ -573,35 +658,67 @@ mod builtins {
             }
         };
 
-        let mut candidates_iter = candidates.into_iter();
-        let mut x = match candidates_iter.next() {
-            Some(x) => x,
-            None => {
-                return default.ok_or_else(|| {
-                    vm.new_value_error(format!("{func_name}() arg is an empty sequence"))
-                });
-            }
-        };
-
+        // Borrowed for a plain list/tuple argument -- avoids cloning every
+        // element up front just to throw away all but the winning one.
         let key_func = key_func.filter(|f| !vm.is_none(f));
-        if let Some(ref key_func) = key_func {
-            let mut x_key = key_func.call((x.clone(),), vm)?;
-            for y in candidates_iter {
-                let y_key = key_func.call((y.clone(),), vm)?;
-                if y_key.rich_compare_bool(&x_key, op, vm)? {
-                    x = y;
-                    x_key = y_key;
+        candidates.with_elements(vm, |candidates| {
+            let mut candidates_iter = candidates.iter();
+            let mut x = match candidates_iter.next() {
+                Some(x) => x.clone(),
+                None => {
+                    return default.ok_or_else(|| {
+                        vm.new_value_error(format!("{func_name}() arg is an empty sequence"))
+                    });
                 }
-            }
-        } else {
-            for y in candidates_iter {
-                if y.rich_compare_bool(&x, op, vm)? {
-                    x = y;
+            };
+
+            if let Some(ref key_func) = key_func {
+                let mut x_key = key_func.call((x.clone(),), vm)?;
+                for y in candidates_iter {
+                    let y_key = key_func.call((y.clone(),), vm)?;
+                    if y_key.rich_compare_bool(&x_key, op, vm)? {
+                        x = y.clone();
+                        x_key = y_key;
+                    }
+                }
+            } else if let Some(kind) = fast_scalar_kind(&x, vm) {
+                // Fast path: compare directly in Rust as long as every element
+                // seen so far is exactly the same int/float/str kind, skipping
+                // `rich_compare_bool`'s dunder-method dispatch entirely. The
+                // moment a differently-typed (or subclassed) element shows up,
+                // drop to the generic protocol dispatch for the rest, starting
+                // from whatever `x` currently is, so a user `__lt__`/`__gt__`
+                // override still gets a chance to run against it.
+                while let Some(y) = candidates_iter.next() {
+                    match fast_scalar_kind(y, vm) {
+                        Some(y_kind) if y_kind == kind => {
+                            if fast_scalar_wins(&x, y, kind, op) {
+                                x = y.clone();
+                            }
+                        }
+                        _ => {
+                            if y.rich_compare_bool(&x, op, vm)? {
+                                x = y.clone();
+                            }
+                            break;
+                        }
+                    }
+                }
+                for y in candidates_iter {
+                    if y.rich_compare_bool(&x, op, vm)? {
+                        x = y.clone();
+                    }
+                }
+            } else {
+                for y in candidates_iter {
+                    if y.rich_compare_bool(&x, op, vm)? {
+                        x = y.clone();
+                    }
                 }
             }
-        }
 
-        Ok(x)
+            Ok(x)
+        })
     }
 
     #[pyfunction]
@@ -638,13 +755,7 @@ mod builtins {
 
     #[pyfunction]
     fn oct(number: ArgIndex, vm: &VirtualMachine) -> PyResult {
-        let n = number.as_bigint();
-        let s = if n.is_negative() {
-            format!("-0o{:o}", n.abs())
-        } else {
-            format!("0o{n:o}")
-        };
-
+        let s = format_index_radix(&number, "0o", |n| format!("{n:o}"));
         Ok(vm.ctx.new_str(s).into())
     }
 
@@ -700,42 +811,62 @@ mod builtins {
     #[derive(Debug, Default, FromArgs)]
     pub struct PrintOptions {
         #[pyarg(named, default)]
-        sep: Option<PyStrRef>,
+        sep: Option<PyObjectRef>,
         #[pyarg(named, default)]
-        end: Option<PyStrRef>,
+        end: Option<PyObjectRef>,
         #[pyarg(named, default = ArgIntoBool::FALSE)]
         flush: ArgIntoBool,
         #[pyarg(named, default)]
         file: Option<PyObjectRef>,
     }
 
+    /// `sep`/`end` accept `None` (meaning "use the default") or a `str`,
+    /// and nothing else; unlike a plain `PyStrRef` argument, this produces
+    /// CPython's exact `<name> must be None or a string, not <type>` message
+    /// instead of a generic downcast error.
+    fn print_sep_or_end(
+        obj: Option<PyObjectRef>,
+        name: &str,
+        vm: &VirtualMachine,
+    ) -> PyResult<Option<PyStrRef>> {
+        let Some(obj) = obj.filter(|obj| !vm.is_none(obj)) else {
+            return Ok(None);
+        };
+        obj.downcast::<PyStr>().map(Some).map_err(|obj| {
+            vm.new_type_error(format!(
+                "{name} must be None or a string, not {}",
+                obj.class().name()
+            ))
+        })
+    }
+
     #[pyfunction]
     pub fn print(objects: PosArgs, options: PrintOptions, vm: &VirtualMachine) -> PyResult<()> {
         let file = match options.file {
             Some(f) => f,
             None => sys::get_stdout(vm)?,
         };
-        let write = |obj: PyStrRef| vm.call_method(&file, "write", (obj,));
 
-        let sep = options
-            .sep
+        let sep = print_sep_or_end(options.sep, "sep", vm)?
             .unwrap_or_else(|| PyStr::from(" ").into_ref(&vm.ctx));
+        let end = print_sep_or_end(options.end, "end", vm)?
+            .unwrap_or_else(|| PyStr::from("\n").into_ref(&vm.ctx));
 
-        let mut first = true;
-        for object in objects {
-            if first {
-                first = false;
-            } else {
-                write(sep.clone())?;
+        // Build the whole line up front so it can go out in a single
+        // `write` call: one write per print() call is faster than one per
+        // object/separator, and keeps a line atomic on buffered/threaded
+        // streams. `str()` on an object is the only part that can still
+        // fail partway through, so do all of that before writing anything.
+        let mut output = String::new();
+        for (i, object) in objects.into_iter().enumerate() {
+            if i > 0 {
+                output.push_str(sep.as_str());
             }
-
-            write(object.str(vm)?)?;
+            output.push_str(object.str(vm)?.as_str());
         }
+        output.push_str(end.as_str());
 
-        let end = options
-            .end
-            .unwrap_or_else(|| PyStr::from("\n").into_ref(&vm.ctx));
-        write(end)?;
+        vm.call_method(&file, "write", (vm.ctx.new_str(output),))?;
 
         if *options.flush {
             vm.call_method(&file, "flush", ())?;
@@ -812,8 +943,8 @@ mod builtins {
     // builtin_slice
 
     #[pyfunction]
-    fn sorted(iterable: PyObjectRef, opts: SortOptions, vm: &VirtualMachine) -> PyResult<PyList> {
-        let items: Vec<_> = iterable.try_to_value(vm)?;
+    fn sorted(iterable: ArgIterable, opts: SortOptions, vm: &VirtualMachine) -> PyResult<PyList> {
+        let items = iterable.try_collect_with_hint(vm)?;
         let lst = PyList::from(items);
         lst.sort(opts, vm)?;
         Ok(lst)
@@ -985,10 +1116,10 @@ mod builtins {
         let classcell = function.invoke_with_locals(().into(), Some(namespace.clone()), vm)?;
         let classcell = <Option<PyCellRef>>::try_from_object(vm, classcell)?;
 
-        if let Some(orig_bases) = orig_bases {
+        if let Some(orig_bases) = &orig_bases {
             namespace.as_object().set_item(
                 identifier!(vm, __orig_bases__),
-                orig_bases.into(),
+                orig_bases.clone().into(),
                 vm,
             )?;
         }
@@ -1001,6 +1132,19 @@ mod builtins {
 
         let args = FuncArgs::new(vec![name_obj.into(), bases, namespace.into()], kwargs);
         let class = metaclass.call(args, vm)?;
+        let class_is_type = class.fast_isinstance(vm.ctx.types.type_type);
+
+        if let Some(orig_bases) = orig_bases {
+            if !class_is_type {
+                // A metaclass that isn't `type` (e.g. a plain function or
+                // other callable) may not copy the namespace mapping onto
+                // the object it returns, so try to set __orig_bases__ on the
+                // class directly too. Best-effort: some non-type results
+                // (e.g. a builtin tuple) don't support attribute assignment
+                // at all, and that's not an error worth surfacing here.
+                let _ = class.set_attr(identifier!(vm, __orig_bases__), orig_bases.into(), vm);
+            }
+        }
 
         // For PEP 695 classes, set __type_params__ on the class from the function
         if let Ok(type_params) = function
@@ -1016,17 +1160,22 @@ mod builtins {
             }
         }
 
-        if let Some(ref classcell) = classcell {
-            let classcell = classcell.get().ok_or_else(|| {
-                vm.new_type_error(format!(
-                    "__class__ not set defining {meta_name:?} as {class:?}. Was __classcell__ propagated to type.__new__?"
-                ))
-            })?;
+        // A metaclass that isn't `type` has no obligation to (and generally
+        // can't) propagate __classcell__ into a `__class__` cell the way
+        // type.__new__ does, so there's nothing meaningful to validate here.
+        if class_is_type {
+            if let Some(ref classcell) = classcell {
+                let classcell = classcell.get().ok_or_else(|| {
+                    vm.new_type_error(format!(
+                        "__class__ not set defining {meta_name:?} as {class:?}. Was __classcell__ propagated to type.__new__?"
+                    ))
+                })?;
 
-            if !classcell.is(&class) {
-                return Err(vm.new_type_error(format!(
-                    "__class__ set to {classcell:?} defining {meta_name:?} as {class:?}"
-                )));
+                if !classcell.is(&class) {
+                    return Err(vm.new_type_error(format!(
+                        "__class__ set to {classcell:?} defining {meta_name:?} as {class:?}"
+                    )));
+                }
             }
         }
 
@@ -94,6 +94,19 @@ mod _imp {
             .new_str(vm.state.settings.check_hash_pycs_mode.to_string())
     }
 
+    #[cfg(all(
+        any(target_os = "linux", target_os = "macos", target_os = "windows"),
+        not(any(target_env = "musl", target_env = "sgx"))
+    ))]
+    #[pyfunction]
+    fn extension_suffixes(vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+        Ok(vec![vm.ctx.new_str(".rpyd").into()])
+    }
+
+    #[cfg(not(all(
+        any(target_os = "linux", target_os = "macos", target_os = "windows"),
+        not(any(target_env = "musl", target_env = "sgx"))
+    )))]
     #[pyfunction]
     const fn extension_suffixes() -> PyResult<Vec<PyObjectRef>> {
         Ok(Vec::new())
@@ -130,6 +143,46 @@ mod _imp {
         0
     }
 
+    #[cfg(all(
+        any(target_os = "linux", target_os = "macos", target_os = "windows"),
+        not(any(target_env = "musl", target_env = "sgx"))
+    ))]
+    #[pyfunction]
+    fn create_dynamic(spec: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let name: PyStrRef = spec.get_attr("name", vm)?.try_into_value(vm)?;
+        let sys_modules = vm.sys_module.get_attr("modules", vm)?;
+        if let Ok(module) = sys_modules.get_item(name.as_str(), vm) {
+            // ExtensionFileLoader.create_module() may be asked to recreate a
+            // module that's already in sys.modules; hand back the cached one
+            // instead of re-running the plugin's native init a second time.
+            return Ok(module);
+        }
+        let origin: PyStrRef = spec.get_attr("origin", vm)?.try_into_value(vm)?;
+        crate::stdlib::native_plugin::load(name.as_str(), origin.as_str(), vm)
+            .map(Into::into)
+            .map_err(|msg| vm.new_import_error(msg, name))
+    }
+
+    #[cfg(not(all(
+        any(target_os = "linux", target_os = "macos", target_os = "windows"),
+        not(any(target_env = "musl", target_env = "sgx"))
+    )))]
+    #[pyfunction]
+    fn create_dynamic(spec: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let name: PyStrRef = spec.get_attr("name", vm)?.try_into_value(vm)?;
+        Err(vm.new_import_error(
+            "dynamic loading of native extension modules is not supported on this platform",
+            name,
+        ))
+    }
+
+    #[pyfunction]
+    fn exec_dynamic(_mod: PyRef<PyModule>) -> i32 {
+        // The plugin's `init` function already fully initializes the module
+        // by the time create_dynamic() returns.
+        0
+    }
+
     #[pyfunction]
     fn get_frozen_object(name: PyStrRef, vm: &VirtualMachine) -> PyResult<PyRef<PyCode>> {
         import::make_frozen(vm, name.as_str())
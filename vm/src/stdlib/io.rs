@@ -137,6 +137,7 @@ mod _io {
             BufferDescriptor, BufferMethods, BufferResizeGuard, PyBuffer, PyIterReturn, VecBuffer,
         },
         recursion::ReprGuard,
+        stdlib::warnings,
         types::{
             Callable, Constructor, DefaultConstructor, Destructor, Initializer, IterNext, Iterable,
         },
@@ -635,32 +636,52 @@ mod _io {
 
         #[pymethod]
         fn readall(instance: PyObjectRef, vm: &VirtualMachine) -> PyResult<Option<Vec<u8>>> {
-            let mut chunks = Vec::new();
-            let mut total_len = 0;
+            // Grow a single buffer geometrically and `readinto()` directly
+            // against its unfilled tail, instead of concatenating a fresh
+            // `bytes` object out of a fixed-size `read()` call every
+            // `DEFAULT_BUFFER_SIZE` bytes: doubling means O(log n) buffer
+            // growths for a stream of length n, with no per-chunk `bytes`
+            // object to allocate and copy out of afterwards. Mirrors
+            // CPython's C-level `readall()`.
+            let mut buf = vec![0u8; DEFAULT_BUFFER_SIZE];
+            let mut total = 0usize;
+            let mut got_any = false;
             loop {
-                let data = vm.call_method(&instance, "read", (DEFAULT_BUFFER_SIZE,))?;
-                let data = <Option<PyBytesRef>>::try_from_object(vm, data)?;
-                match data {
+                if total == buf.len() {
+                    buf.resize(buf.len() * 2, 0);
+                }
+                let buf_range = total..buf.len();
+                let read_buf = VecBuffer::from(std::mem::take(&mut buf)).into_ref(&vm.ctx);
+                let mem_obj = PyMemoryView::from_buffer_range(
+                    read_buf.clone().into_pybuffer(false),
+                    buf_range,
+                    vm,
+                )?
+                .into_ref(&vm.ctx);
+
+                // TODO: loop if readinto() raises an interrupt
+                let res = vm.call_method(&instance, "readinto", (mem_obj.clone(),));
+
+                mem_obj.release();
+                buf = read_buf.take();
+
+                let n = <Option<usize>>::try_from_object(vm, res?)?;
+                match n {
                     None => {
-                        if chunks.is_empty() {
+                        if !got_any {
                             return Ok(None);
                         }
                         break;
                     }
-                    Some(b) => {
-                        if b.as_bytes().is_empty() {
-                            break;
-                        }
-                        total_len += b.as_bytes().len();
-                        chunks.push(b)
+                    Some(0) => break,
+                    Some(n) => {
+                        total += n;
+                        got_any = true;
                     }
                 }
             }
-            let mut ret = Vec::with_capacity(total_len);
-            for b in chunks {
-                ret.extend_from_slice(b.as_bytes())
-            }
-            Ok(Some(ret))
+            buf.truncate(total);
+            Ok(Some(buf))
         }
     }
 
@@ -1154,6 +1175,51 @@ mod _io {
             Ok(Some(out))
         }
 
+        /// Scan for the next line using `memchr` over the internal buffer,
+        /// refilling it as needed, instead of issuing a syscall per byte.
+        fn readline_impl(
+            &mut self,
+            limit: Option<usize>,
+            vm: &VirtualMachine,
+        ) -> PyResult<Vec<u8>> {
+            let mut chunks: Vec<Vec<u8>> = Vec::new();
+            let mut total = 0usize;
+            loop {
+                let have = self.readahead() as usize;
+                if have > 0 {
+                    let slice = self.active_read_slice();
+                    let want = limit.map_or(have, |l| have.min(l - total));
+                    let scan = &slice[..want];
+                    match memchr::memchr(b'\n', scan) {
+                        Some(idx) => {
+                            chunks.push(scan[..=idx].to_vec());
+                            self.pos += (idx + 1) as Offset;
+                            break;
+                        }
+                        None => {
+                            chunks.push(scan.to_vec());
+                            self.pos += want as Offset;
+                            total += want;
+                        }
+                    }
+                }
+                if limit.is_some_and(|l| total >= l) {
+                    break;
+                }
+                if self.writable() {
+                    self.flush_rewind(vm)?;
+                }
+                self.reset_read();
+                let filled = self.fill_buffer(vm)?;
+                self.pos = 0;
+                match filled {
+                    Some(0) | None => break,
+                    Some(_) => {}
+                }
+            }
+            Ok(chunks.concat())
+        }
+
         fn fill_buffer(&mut self, vm: &VirtualMachine) -> PyResult<Option<usize>> {
             let start = if self.valid_read() {
                 self.read_end as usize
@@ -1663,6 +1729,14 @@ mod _io {
             }
         }
 
+        #[pymethod]
+        fn readline(&self, size: OptionalSize, vm: &VirtualMachine) -> PyResult<Vec<u8>> {
+            let mut data = self.reader().lock(vm)?;
+            let raw = data.check_init(vm)?;
+            ensure_unclosed(raw, "readline of closed file", vm)?;
+            data.readline_impl(size.to_usize(), vm)
+        }
+
         #[pymethod]
         fn peek(&self, _size: OptionalSize, vm: &VirtualMachine) -> PyResult<Vec<u8>> {
             let mut data = self.reader().lock(vm)?;
@@ -2013,6 +2087,18 @@ mod _io {
                 }
             }
         }
+
+        /// The literal sequence to substitute for `\n` on write, if any.
+        /// Used by `StringIO`, which -- unlike a real file -- never
+        /// translates `\n` to `os.linesep`: only an explicit `newline='\r'`
+        /// or `newline='\r\n'` triggers write-side translation at all.
+        fn write_translation(&self) -> Option<&'static str> {
+            match self {
+                Self::Cr => Some("\r"),
+                Self::Crlf => Some("\r\n"),
+                Self::Universal | Self::Passthrough | Self::Lf => None,
+            }
+        }
     }
 
     impl TryFromObject for Newlines {
@@ -2293,6 +2379,18 @@ mod _io {
             let mut data = zelf.lock_opt(vm)?;
             *data = None;
 
+            if args.encoding.is_none()
+                && vm.state.settings.utf8_mode == 0
+                && vm.state.settings.warn_default_encoding
+            {
+                warnings::warn(
+                    vm.ctx.exceptions.encoding_warning,
+                    "'encoding' argument not specified".to_owned(),
+                    1,
+                    vm,
+                )?;
+            }
+
             let encoding = match args.encoding {
                 None if vm.state.settings.utf8_mode > 0 => identifier!(vm, utf_8).to_owned(),
                 Some(enc) if enc.as_wtf8() != "locale" => enc,
@@ -3467,6 +3565,7 @@ mod _io {
     struct StringIO {
         buffer: PyRwLock<BufferedIO>,
         closed: AtomicCell<bool>,
+        newline: Newlines,
     }
 
     #[derive(FromArgs)]
@@ -3474,16 +3573,18 @@ mod _io {
         #[pyarg(positional, optional)]
         object: OptionalOption<PyStrRef>,
 
-        // TODO: use this
-        #[pyarg(any, default)]
-        #[allow(dead_code)]
-        newline: Newlines,
+        // Unlike TextIOWrapper (which defaults to universal-newline mode),
+        // StringIO defaults to newline='\n': no translation either way. Only
+        // an explicit newline=None switches on universal-newline reading, so
+        // the "argument omitted" and "argument is None" cases must stay
+        // distinguishable here -- that's why this isn't just `Newlines`.
+        #[pyarg(any, optional)]
+        newline: OptionalArg<Newlines>,
     }
 
     impl Constructor for StringIO {
         type Args = StringIONewArgs;
 
-        #[allow(unused_variables)]
         fn py_new(
             cls: PyTypeRef,
             Self::Args { object, newline }: Self::Args,
@@ -3496,6 +3597,7 @@ mod _io {
             Self {
                 buffer: PyRwLock::new(BufferedIO::new(Cursor::new(raw_bytes))),
                 closed: AtomicCell::new(false),
+                newline: newline.into_option().unwrap_or(Newlines::Lf),
             }
             .into_ref_with_type(vm, cls)
             .map(Into::into)
@@ -3542,7 +3644,25 @@ mod _io {
         // write string to underlying vector
         #[pymethod]
         fn write(&self, data: PyStrRef, vm: &VirtualMachine) -> PyResult<u64> {
-            let bytes = data.as_bytes();
+            let translated;
+            let bytes = match self.newline.write_translation() {
+                // `\n` is never a continuation byte in (w)utf-8, so it's safe
+                // to scan for it directly in the raw byte representation.
+                Some(sep) if memchr::memchr(b'\n', data.as_bytes()).is_some() => {
+                    let input = data.as_bytes();
+                    let mut buf = Vec::with_capacity(input.len());
+                    let mut rest = input;
+                    while let Some(pos) = memchr::memchr(b'\n', rest) {
+                        buf.extend_from_slice(&rest[..pos]);
+                        buf.extend_from_slice(sep.as_bytes());
+                        rest = &rest[pos + 1..];
+                    }
+                    buf.extend_from_slice(rest);
+                    translated = buf;
+                    translated.as_slice()
+                }
+                _ => data.as_bytes(),
+            };
             self.buffer(vm)?
                 .write(bytes)
                 .ok_or_else(|| vm.new_type_error("Error Writing String"))
@@ -4071,17 +4191,37 @@ mod _io {
         .unwrap()
     }
 
+    /// A helper for callers other than `open()` (e.g. stdlib modules that
+    /// open text files) that want to resolve a missing `encoding` argument
+    /// the same way `open()`/`TextIOWrapper` do: fall back to the locale
+    /// encoding (or utf-8 under `-X utf8`) and, if `-X warn_default_encoding`
+    /// or `PYTHONWARNDEFAULTENCODING` is set, warn about the omission with
+    /// `stacklevel` pointing at the caller's caller (one frame further out
+    /// than a warning raised directly from this function would).
     #[pyfunction]
     fn text_encoding(
         encoding: PyObjectRef,
-        _stacklevel: OptionalArg<i32>,
+        stacklevel: OptionalArg<i32>,
         vm: &VirtualMachine,
     ) -> PyResult<PyStrRef> {
-        if vm.is_none(&encoding) {
-            // TODO: This is `locale` encoding - but we don't have locale encoding yet
+        if !vm.is_none(&encoding) {
+            return encoding.try_into_value(vm);
+        }
+        if vm.state.settings.warn_default_encoding {
+            warnings::warn(
+                vm.ctx.exceptions.encoding_warning,
+                "'encoding' argument not specified".to_owned(),
+                stacklevel.unwrap_or(1) as usize + 1,
+                vm,
+            )?;
+        }
+        if vm.state.settings.utf8_mode > 0 {
             return Ok(vm.ctx.new_str("utf-8"));
         }
-        encoding.try_into_value(vm)
+        vm.import("locale", 0)?
+            .get_attr("getencoding", vm)?
+            .call((), vm)?
+            .try_into_value(vm)
     }
 
     #[cfg(test)]
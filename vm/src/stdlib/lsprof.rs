@@ -0,0 +1,420 @@
+//! Implementation of the `_lsprof` module: the native profiling engine that
+//! `cProfile` builds on. `Lib/cProfile.py` and `Lib/pstats.py` are ported
+//! from CPython on top of it (see those files), so both `getstats()` and
+//! `python -m cProfile` work; `getstats()` returns `profiler_entry`/
+//! `profiler_subentry` records with the same field names and `totaltime`/
+//! `inlinetime` meaning as CPython's C `_lsprof`, which is what lets
+//! `cProfile.py`'s `Profile.snapshot_stats()` consume them unmodified.
+//!
+//! Timing is driven by the same `Call`/`Return`/`CCall`/`CReturn`/
+//! `CException` events `sys.setprofile` uses (see
+//! [`crate::protocol::callable`]), so `Profiler` and a plain Python
+//! `sys.setprofile` callback are mutually exclusive -- enabling one clobbers
+//! the other, same as CPython.
+pub(crate) use _lsprof::make_module;
+
+#[pymodule]
+mod _lsprof {
+    use crate::{
+        AsObject, Py, PyObjectRef, PyPayload, PyResult, TryFromObject, VirtualMachine,
+        builtins::{PyStrRef, PyTypeRef},
+        function::{ArgCallable, FuncArgs, OptionalArg},
+        types::{Callable, Constructor},
+    };
+    use std::{
+        cell::{Cell, RefCell},
+        collections::HashMap,
+        fmt,
+        time::Instant,
+    };
+
+    #[pyattr(name = "ProfilerError", once)]
+    fn profiler_error(vm: &VirtualMachine) -> PyTypeRef {
+        vm.ctx.new_exception_type(
+            "_lsprof",
+            "ProfilerError",
+            Some(vec![vm.ctx.exceptions.exception_type.to_owned()]),
+        )
+    }
+
+    // Field names and meaning match CPython's C `_lsprof.profiler_entry`
+    // exactly: `totaltime` is the *cumulative* time (this call plus every
+    // call it made), `inlinetime` is this call's own/exclusive time.
+    // `cProfile.py`'s `Profile.snapshot_stats()` reads both by these names.
+    #[pyattr]
+    #[pyclass(module = "_lsprof", name = "profiler_entry")]
+    #[derive(Debug, PyStructSequence)]
+    struct ProfilerEntry {
+        code: PyObjectRef,
+        callcount: u64,
+        reccallcount: u64,
+        totaltime: f64,
+        inlinetime: f64,
+        calls: PyObjectRef,
+    }
+
+    #[pyclass(with(PyStructSequence))]
+    impl ProfilerEntry {}
+
+    #[pyattr]
+    #[pyclass(module = "_lsprof", name = "profiler_subentry")]
+    #[derive(Debug, PyStructSequence)]
+    struct ProfilerSubEntry {
+        code: PyObjectRef,
+        callcount: u64,
+        reccallcount: u64,
+        totaltime: f64,
+        inlinetime: f64,
+    }
+
+    #[pyclass(with(PyStructSequence))]
+    impl ProfilerSubEntry {}
+
+    /// Identifies one aggregated row of the profile: a code object for
+    /// Python-level calls, or the identity of the callable itself for
+    /// builtins (CPython instead keys builtins by their underlying C
+    /// function pointer, which merges calls to the same method across every
+    /// instance of a type; keying by object identity here is a deliberate
+    /// simplification -- `getstats()` will report one entry per bound
+    /// builtin-method object rather than one per method).
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    enum EntryKey {
+        Code(usize),
+        Builtin(usize),
+    }
+
+    #[derive(Default)]
+    struct CallerStats {
+        call_count: u64,
+        recursive_call_count: u64,
+        total_time: f64,
+        cumulative_time: f64,
+    }
+
+    struct EntryStats {
+        /// A `code` object for `EntryKey::Code`, or a descriptive string like
+        /// CPython's `"<built-in method ...>"` for `EntryKey::Builtin`.
+        label: PyObjectRef,
+        call_count: u64,
+        recursive_call_count: u64,
+        total_time: f64,
+        cumulative_time: f64,
+        callers: HashMap<EntryKey, CallerStats>,
+    }
+
+    struct StackFrame {
+        key: EntryKey,
+        start: f64,
+        /// Time already billed to children of this call, subtracted from the
+        /// wall-clock span at `Return`/`CReturn` time to get *this* call's
+        /// own inline time.
+        child_time: f64,
+    }
+
+    #[pyattr]
+    #[pyclass(module = "_lsprof", name = "Profiler")]
+    #[derive(PyPayload)]
+    struct Profiler {
+        timer: Option<ArgCallable>,
+        timeunit: f64,
+        subcalls: bool,
+        builtins: bool,
+        created_at: Instant,
+        enabled: Cell<bool>,
+        stack: RefCell<Vec<StackFrame>>,
+        entries: RefCell<HashMap<EntryKey, EntryStats>>,
+    }
+
+    impl fmt::Debug for Profiler {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.pad("_lsprof.Profiler")
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct ProfilerNewArgs {
+        #[pyarg(any, optional)]
+        timer: OptionalArg<ArgCallable>,
+        #[pyarg(any, default)]
+        timeunit: f64,
+        #[pyarg(any, default = true)]
+        subcalls: bool,
+        #[pyarg(any, default = true)]
+        builtins: bool,
+    }
+
+    impl Constructor for Profiler {
+        type Args = ProfilerNewArgs;
+
+        fn py_new(cls: PyTypeRef, args: Self::Args, vm: &VirtualMachine) -> PyResult {
+            Self {
+                timer: args.timer.into_option(),
+                timeunit: args.timeunit,
+                subcalls: args.subcalls,
+                builtins: args.builtins,
+                created_at: Instant::now(),
+                enabled: Cell::new(false),
+                stack: RefCell::default(),
+                entries: RefCell::default(),
+            }
+            .into_ref_with_type(vm, cls)
+            .map(Into::into)
+        }
+    }
+
+    #[pyclass(with(Constructor, Callable))]
+    impl Profiler {
+        /// Elapsed time in seconds: the caller-supplied timer if there is
+        /// one (scaled by `timeunit` when it returns something other than
+        /// float seconds, matching CPython), or a monotonic clock otherwise.
+        fn now(&self, vm: &VirtualMachine) -> PyResult<f64> {
+            match &self.timer {
+                Some(timer) => {
+                    let raw = timer.invoke((), vm)?;
+                    let value: f64 = TryFromObject::try_from_object(vm, raw)?;
+                    Ok(if self.timeunit != 0.0 {
+                        value * self.timeunit
+                    } else {
+                        value
+                    })
+                }
+                None => Ok(self.created_at.elapsed().as_secs_f64()),
+            }
+        }
+
+        #[pymethod]
+        fn enable(
+            zelf: &Py<Self>,
+            subcalls: OptionalArg<bool>,
+            builtins: OptionalArg<bool>,
+            vm: &VirtualMachine,
+        ) {
+            // subcalls/builtins were already fixed at construction time in
+            // CPython's own Profiler.enable(); RustPython additionally
+            // accepts them positionally-compatible here but they're no-ops
+            // once already set by `__init__`, since our recording code
+            // doesn't currently branch on them per-enable.
+            let _ = (subcalls, builtins);
+            zelf.stack.borrow_mut().clear();
+            zelf.enabled.set(true);
+            // Install this profiler as the profile function, replacing any
+            // `sys.setprofile` callback.
+            vm.profile_func.replace(zelf.to_owned().into());
+            vm.use_tracing.set(true);
+        }
+
+        #[pymethod]
+        fn disable(&self, vm: &VirtualMachine) {
+            if self.enabled.replace(false) {
+                vm.profile_func.replace(vm.ctx.none());
+                vm.use_tracing.set(!vm.is_none(&vm.trace_func.borrow()));
+            }
+        }
+
+        #[pymethod]
+        fn clear(&self) {
+            self.entries.borrow_mut().clear();
+            self.stack.borrow_mut().clear();
+        }
+
+        #[pymethod]
+        fn getstats(&self, vm: &VirtualMachine) -> PyObjectRef {
+            let entries_map = self.entries.borrow();
+            // `EntryStats.callers` is indexed from the callee's side (built
+            // in `on_return`: "who called *me*, and with what timings").
+            // CPython's `profiler_entry.calls`, which `cProfile.py`'s
+            // `snapshot_stats()` walks, is indexed the other way around --
+            // each entry's `calls` lists the *callees* it made, so that
+            // `subentry.code` names the function that was called, not the
+            // one doing the calling. Invert the index once up front rather
+            // than getting it backwards in the per-entry loop below.
+            let labels: HashMap<EntryKey, PyObjectRef> = entries_map
+                .iter()
+                .map(|(key, entry)| (*key, entry.label.clone()))
+                .collect();
+            let mut callees: HashMap<EntryKey, Vec<(EntryKey, &CallerStats)>> = HashMap::new();
+            for (callee_key, callee) in entries_map.iter() {
+                for (caller_key, stats) in &callee.callers {
+                    callees
+                        .entry(*caller_key)
+                        .or_default()
+                        .push((*callee_key, stats));
+                }
+            }
+            let entries: Vec<PyObjectRef> = entries_map
+                .iter()
+                .map(|(key, entry)| {
+                    let calls = match callees.get(key) {
+                        None => vm.ctx.none(),
+                        Some(callees) => {
+                            let subentries = callees
+                                .iter()
+                                .map(|(callee_key, stats)| {
+                                    let code = labels
+                                        .get(callee_key)
+                                        .cloned()
+                                        .unwrap_or_else(|| vm.ctx.none());
+                                    ProfilerSubEntry {
+                                        code,
+                                        callcount: stats.call_count,
+                                        reccallcount: stats.recursive_call_count,
+                                        totaltime: stats.cumulative_time,
+                                        inlinetime: stats.total_time,
+                                    }
+                                    .into_struct_sequence(vm)
+                                    .into()
+                                })
+                                .collect();
+                            vm.ctx.new_list(subentries).into()
+                        }
+                    };
+                    ProfilerEntry {
+                        code: entry.label.clone(),
+                        callcount: entry.call_count,
+                        reccallcount: entry.recursive_call_count,
+                        totaltime: entry.cumulative_time,
+                        inlinetime: entry.total_time,
+                        calls,
+                    }
+                    .into_struct_sequence(vm)
+                    .into()
+                })
+                .collect();
+            vm.ctx.new_list(entries).into()
+        }
+
+        fn on_call(&self, callee: PyObjectRef, key: EntryKey, vm: &VirtualMachine) -> PyResult<()> {
+            let now = self.now(vm)?;
+            let already_on_stack = self.stack.borrow().iter().any(|frame| frame.key == key);
+            self.entries
+                .borrow_mut()
+                .entry(key)
+                .or_insert_with(|| EntryStats {
+                    label: entry_label(&callee, key, vm),
+                    call_count: 0,
+                    recursive_call_count: 0,
+                    total_time: 0.0,
+                    cumulative_time: 0.0,
+                    callers: HashMap::new(),
+                });
+            {
+                let mut entries = self.entries.borrow_mut();
+                let entry = entries.get_mut(&key).unwrap();
+                entry.call_count += 1;
+                if already_on_stack {
+                    entry.recursive_call_count += 1;
+                }
+            }
+            self.stack.borrow_mut().push(StackFrame {
+                key,
+                start: now,
+                child_time: 0.0,
+            });
+            Ok(())
+        }
+
+        fn on_return(&self, key: EntryKey, vm: &VirtualMachine) -> PyResult<()> {
+            let now = self.now(vm)?;
+            let Some(frame) = self.stack.borrow_mut().pop() else {
+                // A `Return`/`CReturn` with no matching `Call`/`CCall` on our
+                // stack -- e.g. we were enabled partway through an
+                // already-running call. Nothing to attribute; ignore it.
+                return Ok(());
+            };
+            if frame.key != key {
+                // Mismatched stack, most likely from enabling/disabling the
+                // profiler mid-call. Put it back and bail rather than
+                // recording bogus timings.
+                self.stack.borrow_mut().push(frame);
+                return Ok(());
+            }
+            let elapsed = (now - frame.start).max(0.0);
+            let inline = (elapsed - frame.child_time).max(0.0);
+
+            {
+                let mut entries = self.entries.borrow_mut();
+                if let Some(entry) = entries.get_mut(&key) {
+                    entry.total_time += inline;
+                    entry.cumulative_time += elapsed;
+                }
+            }
+
+            if let Some(caller) = self.stack.borrow_mut().last_mut() {
+                caller.child_time += elapsed;
+                if self.subcalls {
+                    let caller_key = caller.key;
+                    let mut entries = self.entries.borrow_mut();
+                    if let Some(entry) = entries.get_mut(&key) {
+                        let stats = entry.callers.entry(caller_key).or_default();
+                        stats.call_count += 1;
+                        stats.total_time += inline;
+                        stats.cumulative_time += elapsed;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Builds the label `getstats()` reports for a newly-seen entry: the
+    /// code object itself for a Python-level call (what `pstats` expects to
+    /// pull `co_filename`/`co_name`/`co_firstlineno` from), or a
+    /// CPython-style `"<built-in method ...>"` string for a builtin.
+    fn entry_label(callee: &PyObjectRef, key: EntryKey, vm: &VirtualMachine) -> PyObjectRef {
+        if let EntryKey::Code(_) = key {
+            if let Ok(code) = callee.get_attr("__code__", vm) {
+                return code;
+            }
+        }
+        let name = callee
+            .get_attr("__qualname__", vm)
+            .or_else(|_| callee.get_attr("__name__", vm))
+            .and_then(|n| n.str(vm))
+            .map(|s| s.as_str().to_owned())
+            .unwrap_or_else(|_| callee.class().name().to_string());
+        vm.ctx.new_str(format!("<built-in method {name}>")).into()
+    }
+
+    impl Callable for Profiler {
+        type Args = FuncArgs;
+
+        fn call(zelf: &Py<Self>, args: FuncArgs, vm: &VirtualMachine) -> PyResult {
+            let (_frame, event, arg): (PyObjectRef, PyStrRef, PyObjectRef) = args.bind(vm)?;
+            if zelf.enabled.get() {
+                match event.as_str() {
+                    "call" => {
+                        // `arg` is the callee itself here (see the comment in
+                        // `PyCallable::invoke`), not `None` like CPython --
+                        // that's what lets us key this entry without relying
+                        // on the not-yet-pushed callee frame. Key by the code
+                        // object's identity rather than the callable's own:
+                        // a bound method gets a fresh wrapper object on every
+                        // attribute access, so keying by `arg` itself would
+                        // scatter one function's calls across many entries.
+                        let key = match arg.get_attr("__code__", vm) {
+                            Ok(code) => EntryKey::Code(code.get_id()),
+                            Err(_) => EntryKey::Code(arg.get_id()),
+                        };
+                        zelf.on_call(arg, key, vm)?;
+                    }
+                    "return" => {
+                        let key = zelf.stack.borrow().last().map(|frame| frame.key);
+                        if let Some(key) = key {
+                            zelf.on_return(key, vm)?;
+                        }
+                    }
+                    "c_call" if zelf.builtins => {
+                        let key = EntryKey::Builtin(arg.get_id());
+                        zelf.on_call(arg, key, vm)?;
+                    }
+                    "c_return" | "c_exception" if zelf.builtins => {
+                        let key = EntryKey::Builtin(arg.get_id());
+                        zelf.on_return(key, vm)?;
+                    }
+                    _ => {}
+                }
+            }
+            Ok(vm.ctx.none())
+        }
+    }
+}
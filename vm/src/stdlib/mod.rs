@@ -9,10 +9,13 @@ mod functools;
 mod imp;
 pub mod io;
 mod itertools;
+mod lsprof;
 mod marshal;
 mod operator;
 // TODO: maybe make this an extension module, if we ever get those
 // mod re;
+#[cfg(feature = "compiler")]
+mod _symtable;
 mod sre;
 mod stat;
 mod string;
@@ -46,6 +49,11 @@ pub mod posix;
 mod ctypes;
 #[cfg(windows)]
 pub(crate) mod msvcrt;
+#[cfg(all(
+    any(target_os = "linux", target_os = "macos", target_os = "windows"),
+    not(any(target_env = "musl", target_env = "sgx"))
+))]
+pub mod native_plugin;
 #[cfg(all(unix, not(any(target_os = "android", target_os = "redox"))))]
 mod pwd;
 pub(crate) mod signal;
@@ -87,6 +95,7 @@ pub fn get_module_inits() -> StdlibMap {
             "_functools" => functools::make_module,
             "itertools" => itertools::make_module,
             "_io" => io::make_module,
+            "_lsprof" => lsprof::make_module,
             "marshal" => marshal::make_module,
             "_operator" => operator::make_module,
             "_signal" => signal::make_module,
@@ -109,6 +118,7 @@ pub fn get_module_inits() -> StdlibMap {
         #[cfg(feature = "compiler")]
         {
             "symtable" => symtable::make_module,
+            "_symtable" => _symtable::make_module,
         }
         #[cfg(any(unix, target_os = "wasi"))]
         {
@@ -0,0 +1,77 @@
+//! Loader for dynamically-linked native extension modules ("plugins").
+//!
+//! A plugin is a cdylib that exports a single `extern "C"` symbol,
+//! [`ENTRY_SYMBOL`], returning a [`PluginEntry`]. `PluginEntry` is `repr(C)`
+//! so it has a stable layout across the FFI boundary, but its `init`
+//! function still returns a `PyRef<PyModule>` built from this exact
+//! `rustpython-vm` build's types — so a plugin only works when compiled
+//! against the same `rustpython-vm` version as the host. [`ABI_VERSION`] is
+//! checked before `init` is ever called, so a mismatched plugin is rejected
+//! with an `ImportError` instead of invoked through an incompatible layout.
+
+use crate::{PyRef, VirtualMachine, builtins::PyModule};
+use rustpython_common::lock::PyRwLock;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// The symbol every plugin cdylib must export.
+pub const ENTRY_SYMBOL: &[u8] = b"_rustpython_plugin_entry\0";
+
+/// Bumped implicitly with the crate version; plugins built against a
+/// different `rustpython-vm` are refused at load time rather than risking
+/// an ABI mismatch.
+pub const ABI_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// [`ABI_VERSION`] with a trailing NUL, so a plugin's entry point can hand it
+/// across the FFI boundary as a `*const c_char` without allocating a
+/// [`std::ffi::CString`] at load time. See `rustpython-plugin`'s
+/// `declare_plugin!` macro.
+pub const ABI_VERSION_CSTR: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+#[repr(C)]
+pub struct PluginEntry {
+    pub abi_version: *const c_char,
+    pub init: extern "C" fn(&VirtualMachine) -> PyRef<PyModule>,
+}
+
+type PluginEntryFn = unsafe extern "C" fn() -> PluginEntry;
+
+rustpython_common::static_cell! {
+    static LOADED: PyRwLock<HashMap<String, libloading::Library>>;
+}
+
+fn loaded() -> &'static PyRwLock<HashMap<String, libloading::Library>> {
+    LOADED.get_or_init(|| PyRwLock::new(HashMap::new()))
+}
+
+/// dlopen `path`, validate its ABI, and run its `init` function to build the
+/// module named `name`. The opened library is kept alive for the life of
+/// the process once loaded successfully, since native code or data it
+/// handed to the VM may still be referenced after this call returns.
+pub fn load(name: &str, path: &str, vm: &VirtualMachine) -> Result<PyRef<PyModule>, String> {
+    let lib = unsafe { libloading::Library::new(path) }
+        .map_err(|e| format!("cannot open native plugin {path}: {e}"))?;
+
+    let entry: PluginEntry = unsafe {
+        let entry_fn: libloading::Symbol<PluginEntryFn> = lib
+            .get(ENTRY_SYMBOL)
+            .map_err(|e| format!("{path} is not a rustpython plugin: {e}"))?;
+        entry_fn()
+    };
+
+    let abi = unsafe { CStr::from_ptr(entry.abi_version) }.to_string_lossy();
+    if abi != ABI_VERSION {
+        return Err(format!(
+            "plugin {path} was built for rustpython-vm {abi}, this interpreter is {ABI_VERSION}"
+        ));
+    }
+
+    let module = (entry.init)(vm);
+    // Loading a name again replaces its map entry; the replaced handle is
+    // leaked rather than dropped, so its library is never unloaded.
+    if let Some(old) = loaded().write().insert(name.to_owned(), lib) {
+        std::mem::forget(old);
+    }
+    Ok(module)
+}
@@ -109,6 +109,12 @@ pub(crate) mod _signal {
     #[pyattr]
     use libc::{SIGPWR, SIGSTKFLT};
 
+    // Not real signals -- `GenerateConsoleCtrlEvent()` pseudo-signums accepted
+    // by `os.kill()` to signal a whole console process group on Windows.
+    #[cfg(windows)]
+    #[pyattr]
+    use windows_sys::Win32::System::Console::{CTRL_BREAK_EVENT, CTRL_C_EVENT};
+
     #[cfg(any(unix, windows))]
     pub(super) fn init_signal_handlers(
         module: &Py<crate::builtins::PyModule>,
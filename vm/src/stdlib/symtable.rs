@@ -3,7 +3,10 @@ pub(crate) use symtable::make_module;
 #[pymodule]
 mod symtable {
     use crate::{
-        PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine, builtins::PyStrRef, compiler,
+        PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
+        builtins::{PyStrRef, PyTupleRef},
+        common::lock::OnceCell,
+        compiler,
     };
     use rustpython_codegen::symboltable::{
         CompilerScope, Symbol, SymbolFlags, SymbolScope, SymbolTable,
@@ -17,20 +20,38 @@ mod symtable {
         mode: PyStrRef,
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<PySymbolTable>> {
-        let mode = mode
-            .as_str()
-            .parse::<compiler::Mode>()
-            .map_err(|err| vm.new_value_error(err.to_string()))?;
+        // `symtable.symtable()` accepts the same three modes as `compile()`,
+        // but reports CPython's own wording on an invalid one.
+        let mode = mode.as_str().parse::<compiler::Mode>().map_err(|_| {
+            vm.new_value_error("symtable() arg 3 must be 'exec', 'eval' or 'single'")
+        })?;
 
         let symtable = compiler::compile_symtable(source.as_str(), mode, filename.as_str())
             .map_err(|err| vm.new_syntax_error(&err, Some(source.as_str())))?;
 
-        let py_symbol_table = to_py_symbol_table(symtable);
+        let py_symbol_table = to_py_symbol_table(symtable, filename);
         Ok(py_symbol_table.into_ref(&vm.ctx))
     }
 
-    const fn to_py_symbol_table(symtable: SymbolTable) -> PySymbolTable {
-        PySymbolTable { symtable }
+    fn to_py_symbol_table(symtable: SymbolTable, filename: PyStrRef) -> PySymbolTable {
+        PySymbolTable {
+            symtable,
+            filename,
+            function_info: FunctionInfoCache::default(),
+        }
+    }
+
+    /// Lazily-computed, memoized identifier lists for the `Function`-only
+    /// accessors below (`get_parameters` and friends), mirroring CPython's
+    /// `symtable.Function` which caches each one the first time it's asked
+    /// for.
+    #[derive(Default)]
+    struct FunctionInfoCache {
+        parameters: OnceCell<Vec<String>>,
+        locals: OnceCell<Vec<String>>,
+        globals: OnceCell<Vec<String>>,
+        nonlocals: OnceCell<Vec<String>>,
+        frees: OnceCell<Vec<String>>,
     }
 
     #[pyattr]
@@ -38,6 +59,8 @@ mod symtable {
     #[derive(PyPayload)]
     struct PySymbolTable {
         symtable: SymbolTable,
+        filename: PyStrRef,
+        function_info: FunctionInfoCache,
     }
 
     impl fmt::Debug for PySymbolTable {
@@ -48,6 +71,35 @@ mod symtable {
 
     #[pyclass]
     impl PySymbolTable {
+        /// The filename passed to `symtable()`, exposed the same way
+        /// CPython's pure-Python `symtable.SymbolTable` stashes it in
+        /// `self._filename`.
+        #[pygetset(name = "_filename")]
+        fn filename(&self) -> PyStrRef {
+            self.filename.clone()
+        }
+
+        #[pymethod]
+        fn __repr__(&self) -> String {
+            let kind = match self.symtable.typ {
+                CompilerScope::Function
+                | CompilerScope::AsyncFunction
+                | CompilerScope::Lambda
+                | CompilerScope::Comprehension => "Function ",
+                CompilerScope::Class => "Class ",
+                CompilerScope::Module | CompilerScope::TypeParams => "",
+            };
+            if self.symtable.name == "top" {
+                format!("<{kind}SymbolTable for module {}>", self.filename.as_str())
+            } else {
+                format!(
+                    "<{kind}SymbolTable for {} in {}>",
+                    self.symtable.name,
+                    self.filename.as_str()
+                )
+            }
+        }
+
         #[pymethod]
         fn get_name(&self) -> String {
             self.symtable.name.clone()
@@ -63,6 +115,14 @@ mod symtable {
             self.symtable.line_number
         }
 
+        /// A unique identifier for this table -- the same underlying block
+        /// always reports the same id, however it was reached (e.g. via
+        /// `get_children()` or `lookup(...).get_namespaces()`).
+        #[pymethod]
+        const fn get_id(&self) -> usize {
+            self.symtable.id
+        }
+
         #[pymethod]
         const fn is_nested(&self) -> bool {
             self.symtable.is_nested
@@ -72,16 +132,138 @@ mod symtable {
         fn is_optimized(&self) -> bool {
             matches!(
                 self.symtable.typ,
-                CompilerScope::Function | CompilerScope::AsyncFunction
+                CompilerScope::Function
+                    | CompilerScope::AsyncFunction
+                    | CompilerScope::Comprehension
             )
         }
 
+        /// CPython exposes these five only on the `Function` subclass of
+        /// `SymbolTable`, so calling them on e.g. a module or class scope is
+        /// a hard error there. We have a single `SymbolTable` class, so
+        /// enforce the same restriction with an explicit check instead.
+        fn require_function_scope(&self, vm: &VirtualMachine) -> PyResult<()> {
+            if self.is_optimized() {
+                Ok(())
+            } else {
+                Err(vm.new_type_error(format!(
+                    "this is available only for functions, not a {}",
+                    self.symtable.typ
+                )))
+            }
+        }
+
+        fn idents_matching(
+            &self,
+            cache: &OnceCell<Vec<String>>,
+            test: impl Fn(&Symbol) -> bool,
+        ) -> Vec<String> {
+            cache
+                .get_or_init(|| {
+                    self.symtable
+                        .symbols
+                        .iter()
+                        .filter(|(_, symbol)| test(symbol))
+                        .map(|(name, _)| name.clone())
+                        .collect()
+                })
+                .clone()
+        }
+
+        fn idents_to_tuple(names: Vec<String>, vm: &VirtualMachine) -> PyTupleRef {
+            let elements = names
+                .into_iter()
+                .map(|name| vm.ctx.new_str(name).into())
+                .collect();
+            vm.ctx.new_tuple(elements)
+        }
+
+        /// Return a tuple of the function's parameters, in declaration order.
+        #[pymethod]
+        fn get_parameters(&self, vm: &VirtualMachine) -> PyResult<PyTupleRef> {
+            self.require_function_scope(vm)?;
+            let names = self.idents_matching(&self.function_info.parameters, |symbol| {
+                symbol.flags.contains(SymbolFlags::PARAMETER)
+            });
+            Ok(Self::idents_to_tuple(names, vm))
+        }
+
+        /// Return a tuple of the function's local names (including its
+        /// parameters), in declaration order.
+        #[pymethod]
+        fn get_locals(&self, vm: &VirtualMachine) -> PyResult<PyTupleRef> {
+            self.require_function_scope(vm)?;
+            let names =
+                self.idents_matching(&self.function_info.locals, |symbol| symbol.is_local());
+            Ok(Self::idents_to_tuple(names, vm))
+        }
+
+        /// Return a tuple of the names the function refers to as globals, in
+        /// declaration order.
+        #[pymethod]
+        fn get_globals(&self, vm: &VirtualMachine) -> PyResult<PyTupleRef> {
+            self.require_function_scope(vm)?;
+            let names =
+                self.idents_matching(&self.function_info.globals, |symbol| symbol.is_global());
+            Ok(Self::idents_to_tuple(names, vm))
+        }
+
+        /// Return a tuple of the function's explicit `nonlocal` names, in
+        /// declaration order.
+        #[pymethod]
+        fn get_nonlocals(&self, vm: &VirtualMachine) -> PyResult<PyTupleRef> {
+            self.require_function_scope(vm)?;
+            let names = self.idents_matching(&self.function_info.nonlocals, |symbol| {
+                symbol.flags.contains(SymbolFlags::NONLOCAL)
+            });
+            Ok(Self::idents_to_tuple(names, vm))
+        }
+
+        /// Return a tuple of the free variables the function closes over, in
+        /// declaration order.
+        #[pymethod]
+        fn get_frees(&self, vm: &VirtualMachine) -> PyResult<PyTupleRef> {
+            self.require_function_scope(vm)?;
+            let names = self.idents_matching(&self.function_info.frees, |symbol| {
+                matches!(symbol.scope, SymbolScope::Free)
+            });
+            Ok(Self::idents_to_tuple(names, vm))
+        }
+
+        /// Return a tuple of the names of the class's direct child blocks
+        /// (`def`s, `async def`s, lambdas and nested classes alike -- CPython
+        /// doesn't distinguish between them here), in declaration order and
+        /// without duplicates.
+        #[pymethod]
+        fn get_methods(&self, vm: &VirtualMachine) -> PyResult<PyTupleRef> {
+            if self.symtable.typ != CompilerScope::Class {
+                return Err(vm.new_type_error(format!(
+                    "this is available only for classes, not a {}",
+                    self.symtable.typ
+                )));
+            }
+            let mut names = Vec::new();
+            for table in &self.symtable.sub_tables {
+                if !names.contains(&table.name) {
+                    names.push(table.name.clone());
+                }
+            }
+            Ok(Self::idents_to_tuple(names, vm))
+        }
+
         #[pymethod]
         fn lookup(&self, name: PyStrRef, vm: &VirtualMachine) -> PyResult<PyRef<PySymbol>> {
             let name = name.as_str();
             if let Some(symbol) = self.symtable.symbols.get(name) {
                 Ok(PySymbol {
                     symbol: symbol.clone(),
+                    // A sub-table's `name` is always the literal identifier from the
+                    // `def`/`class` statement that created it, and that same statement is
+                    // what binds the symbol of the same name in this scope -- so filtering
+                    // on name can't pick up an unrelated table, even when a class and a
+                    // function (or two `def`s) share a name and legitimately produce more
+                    // than one namespace for the same symbol (matching CPython, which also
+                    // considers such a symbol "bound to multiple namespaces").
                     namespaces: self
                         .symtable
                         .sub_tables
@@ -90,6 +272,7 @@ mod symtable {
                         .cloned()
                         .collect(),
                     is_top_scope: self.symtable.name == "top",
+                    filename: self.filename.clone(),
                 }
                 .into_ref(&vm.ctx))
             } else {
@@ -125,6 +308,7 @@ mod symtable {
                             .cloned()
                             .collect(),
                         is_top_scope: self.symtable.name == "top",
+                        filename: self.filename.clone(),
                     })
                     .into_ref(&vm.ctx)
                     .into()
@@ -144,7 +328,7 @@ mod symtable {
                 .symtable
                 .sub_tables
                 .iter()
-                .map(|t| to_py_symbol_table(t.clone()).into_pyobject(vm))
+                .map(|t| to_py_symbol_table(t.clone(), self.filename.clone()).into_pyobject(vm))
                 .collect();
             Ok(children)
         }
@@ -157,6 +341,7 @@ mod symtable {
         symbol: Symbol,
         namespaces: Vec<SymbolTable>,
         is_top_scope: bool,
+        filename: PyStrRef,
     }
 
     impl fmt::Debug for PySymbol {
@@ -167,6 +352,11 @@ mod symtable {
 
     #[pyclass]
     impl PySymbol {
+        #[pymethod]
+        fn __repr__(&self) -> String {
+            format!("<symbol {:?}>", self.symbol.name)
+        }
+
         #[pymethod]
         fn get_name(&self) -> String {
             self.symbol.name.clone()
@@ -194,8 +384,13 @@ mod symtable {
 
         #[pymethod]
         const fn is_nested(&self) -> bool {
-            // TODO
-            false
+            // A symbol is nested when it crosses a function boundary: either
+            // it's a free variable pulled in from an enclosing scope, or it's
+            // local here but captured as a cell by some nested scope (i.e.
+            // free in a grandchild) -- including the class-method special
+            // case tracked by FREE_CLASS.
+            matches!(self.symbol.scope, SymbolScope::Free | SymbolScope::Cell)
+                || self.symbol.flags.contains(SymbolFlags::FREE_CLASS)
         }
 
         #[pymethod]
@@ -203,6 +398,15 @@ mod symtable {
             self.symbol.flags.contains(SymbolFlags::NONLOCAL)
         }
 
+        /// RustPython extension (CPython's `_symtable` has no equivalent):
+        /// true for a comprehension/generator-expression's own iteration
+        /// variable (the `x` in `[... for x in it]`), as opposed to some
+        /// other binding that happens to live in that scope.
+        #[pymethod]
+        const fn is_comp_iter(&self) -> bool {
+            self.symbol.flags.contains(SymbolFlags::ITER)
+        }
+
         #[pymethod]
         const fn is_referenced(&self) -> bool {
             self.symbol.flags.contains(SymbolFlags::REFERENCED)
@@ -238,7 +442,9 @@ mod symtable {
             let namespaces = self
                 .namespaces
                 .iter()
-                .map(|table| to_py_symbol_table(table.clone()).into_pyobject(vm))
+                .map(|table| {
+                    to_py_symbol_table(table.clone(), self.filename.clone()).into_pyobject(vm)
+                })
                 .collect();
             Ok(namespaces)
         }
@@ -248,9 +454,12 @@ mod symtable {
             if self.namespaces.len() != 1 {
                 return Err(vm.new_value_error("namespace is bound to multiple namespaces"));
             }
-            Ok(to_py_symbol_table(self.namespaces.first().unwrap().clone())
-                .into_ref(&vm.ctx)
-                .into())
+            Ok(to_py_symbol_table(
+                self.namespaces.first().unwrap().clone(),
+                self.filename.clone(),
+            )
+            .into_ref(&vm.ctx)
+            .into())
         }
     }
 }
@@ -103,20 +103,32 @@ mod sys {
         if cfg!(windows) { "C:" } else { "/usr/local" }
     }
     #[pyattr]
-    fn prefix(vm: &VirtualMachine) -> &'static str {
-        option_env!("RUSTPYTHON_PREFIX").unwrap_or_else(|| default_prefix(vm))
+    fn prefix(vm: &VirtualMachine) -> String {
+        // RUSTPYTHONHOME/PYTHONHOME, if set, overrides the compile-time default the
+        // same way CPython's PYTHONHOME overrides its baked-in prefix.
+        vm.state.settings.home.clone().unwrap_or_else(|| {
+            option_env!("RUSTPYTHON_PREFIX")
+                .unwrap_or_else(|| default_prefix(vm))
+                .to_owned()
+        })
     }
     #[pyattr]
-    fn base_prefix(vm: &VirtualMachine) -> &'static str {
-        option_env!("RUSTPYTHON_BASEPREFIX").unwrap_or_else(|| prefix(vm))
+    fn base_prefix(vm: &VirtualMachine) -> String {
+        vm.state.settings.home.clone().unwrap_or_else(|| {
+            option_env!("RUSTPYTHON_BASEPREFIX").map_or_else(|| prefix(vm), str::to_owned)
+        })
     }
     #[pyattr]
-    fn exec_prefix(vm: &VirtualMachine) -> &'static str {
-        option_env!("RUSTPYTHON_BASEPREFIX").unwrap_or_else(|| prefix(vm))
+    fn exec_prefix(vm: &VirtualMachine) -> String {
+        vm.state.settings.home.clone().unwrap_or_else(|| {
+            option_env!("RUSTPYTHON_BASEPREFIX").map_or_else(|| prefix(vm), str::to_owned)
+        })
     }
     #[pyattr]
-    fn base_exec_prefix(vm: &VirtualMachine) -> &'static str {
-        option_env!("RUSTPYTHON_BASEPREFIX").unwrap_or_else(|| exec_prefix(vm))
+    fn base_exec_prefix(vm: &VirtualMachine) -> String {
+        vm.state.settings.home.clone().unwrap_or_else(|| {
+            option_env!("RUSTPYTHON_BASEPREFIX").map_or_else(|| exec_prefix(vm), str::to_owned)
+        })
     }
     #[pyattr]
     fn platlibdir(_vm: &VirtualMachine) -> &'static str {
@@ -516,6 +528,24 @@ mod sys {
         Ok(frame.clone())
     }
 
+    /// Return a dictionary mapping each currently-running thread's identifier
+    /// (as returned by `_thread.get_ident()`) to the topmost frame currently
+    /// running in that thread.
+    ///
+    /// This is a snapshot: a thread may have already exited, or moved on to a
+    /// different frame, by the time the caller looks at the result.
+    #[pyfunction]
+    fn _current_frames(vm: &VirtualMachine) -> PyDictRef {
+        let ctx = &vm.ctx;
+        let dict = ctx.new_dict();
+        for (thread_id, frame) in vm.state.thread_frames.lock().iter() {
+            let key = ctx.new_int(*thread_id);
+            dict.set_item(key.as_object(), frame.clone().into(), vm)
+                .expect("dict.__setitem__ should not fail here");
+        }
+        dict
+    }
+
     #[pyfunction]
     fn _getframemodulename(depth: OptionalArg<usize>, vm: &VirtualMachine) -> PyResult {
         let depth = depth.into_option().unwrap_or(0);
@@ -808,6 +838,34 @@ mod sys {
         update_use_tracing(vm);
     }
 
+    /// A native-speed complement to `settrace` for bdb-style debuggers:
+    /// install a set of `(filename, lineno)` pairs that are the only lines
+    /// the trace function actually needs to see (e.g. active breakpoints),
+    /// so the interpreter can skip the trace call for everything else.
+    /// Pass `None` to go back to offering every line to the trace function.
+    #[pyfunction]
+    fn _settrace_skip_unless_breakpoint(
+        breakpoints: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let breakpoints = if vm.is_none(&breakpoints) {
+            None
+        } else {
+            let mut set = std::collections::HashSet::new();
+            for item in breakpoints.try_to_value::<Vec<PyObjectRef>>(vm)? {
+                let pair: Vec<PyObjectRef> = item.try_to_value(vm)?;
+                let [filename, lineno] = <[PyObjectRef; 2]>::try_from(pair)
+                    .map_err(|_| vm.new_value_error("expected (filename, lineno) pairs"))?;
+                let filename = filename.str(vm)?.as_str().to_owned();
+                let lineno = lineno.try_into_value::<u32>(vm)?;
+                set.insert((filename, lineno));
+            }
+            Some(set)
+        };
+        vm.trace_skip_lines.replace(breakpoints);
+        Ok(())
+    }
+
     #[cfg(feature = "threading")]
     #[pyattr]
     fn thread_info(vm: &VirtualMachine) -> PyTupleRef {
@@ -15,7 +15,10 @@ pub(crate) mod _sysconfigdata {
         sysvars! {
             // fake shared module extension
             "EXT_SUFFIX" => format!(".rustpython-{MULTIARCH}"),
+            "SOABI" => format!("rustpython-{MULTIARCH}"),
             "MULTIARCH" => MULTIARCH,
+            // RustPython doesn't distinguish debug/release builds at this level
+            "Py_DEBUG" => 0,
             // enough for tests to stop expecting urandom() to fail after restricting file resources
             "HAVE_GETRANDOM" => 1,
         }
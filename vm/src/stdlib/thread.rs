@@ -1,12 +1,13 @@
 //! Implementation of the _thread module
 #[cfg_attr(target_arch = "wasm32", allow(unused_imports))]
-pub(crate) use _thread::{RawRMutex, make_module};
+pub(crate) use _thread::{RawRMutex, make_module, thread_to_id};
 
 #[pymodule]
 pub(crate) mod _thread {
     use crate::{
         AsObject, Py, PyPayload, PyRef, PyResult, VirtualMachine,
         builtins::{PyDictRef, PyStr, PyTupleRef, PyTypeRef},
+        common::lock::PyMutex,
         convert::ToPyException,
         function::{ArgCallable, Either, FuncArgs, KwArgs, OptionalArg, PySetterValue},
         types::{Constructor, GetAttr, Representable, SetAttr},
@@ -262,7 +263,7 @@ pub(crate) mod _thread {
         thread_to_id(&thread::current())
     }
 
-    fn thread_to_id(t: &thread::Thread) -> u64 {
+    pub(crate) fn thread_to_id(t: &thread::Thread) -> u64 {
         use std::hash::{Hash, Hasher};
         struct U64Hash {
             v: Option<u64>,
@@ -341,6 +342,14 @@ pub(crate) mod _thread {
                 }
             }
         });
+        // `with_frame` clears this thread's entry as its frames unwind, but drop it
+        // here too in case the thread is exiting through some path that skips that
+        // (e.g. it never entered a frame at all), so it doesn't linger in
+        // `sys._current_frames()` after the thread is gone.
+        vm.state
+            .thread_frames
+            .lock()
+            .remove(&thread_to_id(&thread::current()));
         vm.state.thread_count.fetch_sub(1);
     }
 
@@ -383,27 +392,64 @@ pub(crate) mod _thread {
     #[derive(Debug, PyPayload)]
     struct Local {
         data: ThreadLocal<PyDictRef>,
+        // Original constructor args, replayed through a subclass's __init__ the
+        // first time each new thread touches this object -- this is how
+        // threading.local lets a subclass initialize its own per-thread state.
+        args: PyMutex<FuncArgs>,
     }
 
     #[pyclass(with(GetAttr, SetAttr), flags(BASETYPE))]
     impl Local {
-        fn l_dict(&self, vm: &VirtualMachine) -> PyDictRef {
-            self.data.get_or(|| vm.ctx.new_dict()).clone()
+        // Fetches (creating if necessary) this thread's dict for `zelf`. On first
+        // creation for a thread other than the one the object was constructed on,
+        // replays `type(zelf).__init__(zelf, *args, **kwargs)` with the original
+        // construction arguments, exactly as CPython's `threading.local` does.
+        fn l_dict(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyDictRef> {
+            if let Some(dict) = zelf.data.get() {
+                return Ok(dict.clone());
+            }
+            let dict = vm.ctx.new_dict();
+            zelf.data.get_or(|| dict.clone());
+            let init = zelf.class().get_attr(identifier!(vm, __init__));
+            let object_init = vm.ctx.types.object_type.get_attr(identifier!(vm, __init__));
+            if let (Some(init), Some(object_init)) = (init, object_init) {
+                if !init.is(&object_init) {
+                    let args = zelf.args.lock().clone();
+                    vm.call_method(zelf.as_object(), "__init__", args)?;
+                }
+            }
+            Ok(dict)
         }
 
         #[pyslot]
-        fn slot_new(cls: PyTypeRef, _args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-            Self {
-                data: ThreadLocal::new(),
+        fn slot_new(cls: PyTypeRef, args: FuncArgs, vm: &VirtualMachine) -> PyResult {
+            if !args.args.is_empty() || !args.kwargs.is_empty() {
+                let init = cls.get_attr(identifier!(vm, __init__));
+                let object_init = vm.ctx.types.object_type.get_attr(identifier!(vm, __init__));
+                if let (Some(init), Some(object_init)) = (init, object_init) {
+                    if init.is(&object_init) {
+                        return Err(vm.new_type_error(
+                            "Initialization arguments are not supported".to_owned(),
+                        ));
+                    }
+                }
             }
-            .into_ref_with_type(vm, cls)
-            .map(Into::into)
+            let zelf = Self {
+                data: ThreadLocal::new(),
+                args: PyMutex::new(args),
+            };
+            // Pre-create the constructing thread's dict now, so that the
+            // `__init__` call that normally follows `__new__` doesn't get
+            // mistaken by `l_dict` for a *new* thread's first access and
+            // replayed a second time.
+            zelf.data.get_or(|| vm.ctx.new_dict());
+            zelf.into_ref_with_type(vm, cls).map(Into::into)
         }
     }
 
     impl GetAttr for Local {
         fn getattro(zelf: &Py<Self>, attr: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
-            let l_dict = zelf.l_dict(vm);
+            let l_dict = Self::l_dict(zelf, vm)?;
             if attr.as_str() == "__dict__" {
                 Ok(l_dict.into())
             } else {
@@ -433,7 +479,7 @@ pub(crate) mod _thread {
                     zelf.class().name()
                 )))
             } else {
-                let dict = zelf.l_dict(vm);
+                let dict = Self::l_dict(zelf, vm)?;
                 if let PySetterValue::Assign(value) = value {
                     dict.set_item(attr, value, vm)?;
                 } else {
@@ -10,17 +10,44 @@ pub(crate) use _weakref::make_module;
 mod _weakref {
     use crate::{
         PyObjectRef, PyResult, VirtualMachine,
-        builtins::{PyDictRef, PyTypeRef, PyWeak},
+        builtins::{
+            PyDictRef, PyTypeRef, PyWeak, PyWeakCallableProxy, PyWeakProxy,
+            weakproxy::WeakProxyNewArgs,
+        },
+        function::OptionalArg,
+        types::Constructor,
     };
 
     #[pyattr(name = "ref")]
     fn ref_(vm: &VirtualMachine) -> PyTypeRef {
         vm.ctx.types.weakref_type.to_owned()
     }
-    #[pyattr]
-    fn proxy(vm: &VirtualMachine) -> PyTypeRef {
-        vm.ctx.types.weakproxy_type.to_owned()
+
+    /// Like CPython, `proxy()` hands back a callable proxy (supporting
+    /// `__call__`) when the referent is itself callable, and a plain proxy
+    /// otherwise -- unlike `ref()`, `proxy()` is a function rather than a
+    /// type, since which type it returns depends on the referent.
+    #[pyfunction]
+    fn proxy(
+        referent: PyObjectRef,
+        callback: OptionalArg<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult {
+        if referent.is_callable() {
+            PyWeakCallableProxy::py_new(
+                vm.ctx.types.weakcallableproxy_type.to_owned(),
+                WeakProxyNewArgs { referent, callback },
+                vm,
+            )
+        } else {
+            PyWeakProxy::py_new(
+                vm.ctx.types.weakproxy_type.to_owned(),
+                WeakProxyNewArgs { referent, callback },
+                vm,
+            )
+        }
     }
+
     #[pyattr(name = "ReferenceType")]
     fn reference_type(vm: &VirtualMachine) -> PyTypeRef {
         vm.ctx.types.weakref_type.to_owned()
@@ -31,7 +58,7 @@ mod _weakref {
     }
     #[pyattr(name = "CallableProxyType")]
     fn callable_proxy_type(vm: &VirtualMachine) -> PyTypeRef {
-        vm.ctx.types.weakproxy_type.to_owned()
+        vm.ctx.types.weakcallableproxy_type.to_owned()
     }
 
     #[pyfunction]
@@ -37,6 +37,7 @@ pub struct TypeZoo {
     pub filter_type: &'static Py<PyType>,
     pub float_type: &'static Py<PyType>,
     pub frame_type: &'static Py<PyType>,
+    pub frame_locals_proxy_type: &'static Py<PyType>,
     pub frozenset_type: &'static Py<PyType>,
     pub generator_type: &'static Py<PyType>,
     pub int_type: &'static Py<PyType>,
@@ -83,6 +84,7 @@ pub struct TypeZoo {
     pub bound_method_type: &'static Py<PyType>,
     pub weakref_type: &'static Py<PyType>,
     pub weakproxy_type: &'static Py<PyType>,
+    pub weakcallableproxy_type: &'static Py<PyType>,
     pub mappingproxy_type: &'static Py<PyType>,
     pub traceback_type: &'static Py<PyType>,
     pub object_type: &'static Py<PyType>,
@@ -160,6 +162,7 @@ impl TypeZoo {
             dict_reverseitemiterator_type: dict::PyDictReverseItemIterator::init_builtin_type(),
             ellipsis_type: slice::PyEllipsis::init_builtin_type(),
             frame_type: crate::frame::Frame::init_builtin_type(),
+            frame_locals_proxy_type: crate::builtins::frame::FrameLocalsProxy::init_builtin_type(),
             function_type: function::PyFunction::init_builtin_type(),
             generator_type: generator::PyGenerator::init_builtin_type(),
             getset_type: getset::PyGetSet::init_builtin_type(),
@@ -178,6 +181,7 @@ impl TypeZoo {
             traceback_type: traceback::PyTraceback::init_builtin_type(),
             tuple_iterator_type: tuple::PyTupleIterator::init_builtin_type(),
             weakproxy_type: weakproxy::PyWeakProxy::init_builtin_type(),
+            weakcallableproxy_type: weakproxy::PyWeakCallableProxy::init_builtin_type(),
             method_descriptor_type: descriptor::PyMethodDescriptor::init_builtin_type(),
             none_type: singletons::PyNone::init_builtin_type(),
             typing_no_default_type: crate::stdlib::typing::NoDefault::init_builtin_type(),
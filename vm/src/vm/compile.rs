@@ -44,16 +44,18 @@ impl VirtualMachine {
             self.insert_sys_path(self.new_pyobj(dir))?;
         }
 
-        match std::fs::read_to_string(path) {
-            Ok(source) => {
-                self.run_code_string(scope, &source, path.to_owned())?;
-            }
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
             Err(err) => {
                 error!("Failed reading file '{path}': {err}");
                 // TODO: Need to change to ExitCode or Termination
                 std::process::exit(1);
             }
-        }
+        };
+        // Honor PEP 263 coding cookies and a UTF-8 BOM the same way
+        // `compile()` does on bytes source, rather than assuming UTF-8.
+        let source = crate::codecs::decode_source_bytes(&bytes, Some(path), self)?;
+        self.run_code_string(scope, &source, path.to_owned())?;
         Ok(())
     }
 
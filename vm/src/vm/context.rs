@@ -20,14 +20,14 @@ use crate::{
         HeapMethodDef, IntoPyGetterFunc, IntoPyNativeFn, IntoPySetterFunc, PyMethodDef,
         PyMethodFlags,
     },
-    intern::{InternableString, MaybeInternedString, StringPool},
+    intern::{InternableString, MaybeInternedString, StringPool, WeakValueCache},
     object::{Py, PyObjectPayload, PyObjectRef, PyPayload, PyRef},
     types::{PyTypeFlags, PyTypeSlots, TypeZoo},
 };
 use malachite_bigint::BigInt;
 use num_complex::Complex64;
 use num_traits::ToPrimitive;
-use rustpython_common::lock::PyRwLock;
+use rustpython_common::{lock::PyRwLock, wtf8::Wtf8};
 
 #[derive(Debug)]
 pub struct Context {
@@ -48,6 +48,8 @@ pub struct Context {
     pub int_cache_pool: Vec<PyIntRef>,
     // there should only be exact objects of str in here, no non-str objects and no subclasses
     pub(crate) string_pool: StringPool,
+    // shares storage for long string constants across code objects without interning them forever
+    pub(crate) constant_str_cache: WeakValueCache,
     pub(crate) slot_new_wrapper: PyMethodDef,
     pub names: ConstName,
 }
@@ -342,6 +344,7 @@ impl Context {
             exceptions,
             int_cache_pool,
             string_pool,
+            constant_str_cache: WeakValueCache::default(),
             slot_new_wrapper,
             names,
         }
@@ -351,6 +354,15 @@ impl Context {
         unsafe { self.string_pool.intern(s, self.types.str_type.to_owned()) }
     }
 
+    /// Reuse a still-live `str` object equal to `value` if one exists rather
+    /// than allocating a fresh one, without interning it forever. Meant for
+    /// constants (e.g. long string literals in `co_consts`) that are worth
+    /// deduplicating but, unlike identifiers, aren't guaranteed to live for
+    /// the process lifetime.
+    pub fn cache_constant_str(&self, value: &Wtf8) -> PyObjectRef {
+        self.constant_str_cache.get_or_insert_str(value, self)
+    }
+
     pub fn interned_str<S: MaybeInternedString + ?Sized>(
         &self,
         s: &S,
@@ -106,9 +106,10 @@ impl Interpreter {
 
     /// Finalize vm and turns an exception to exit code.
     ///
-    /// Finalization steps including 4 steps:
+    /// Finalization steps including 5 steps:
     /// 1. Flush stdout and stderr.
     /// 1. Handle exit exception and turn it to exit code.
+    /// 1. Wait for non-daemon threads to finish (`threading._shutdown`).
     /// 1. Run atexit exit functions.
     /// 1. Mark vm as finalized.
     ///
@@ -124,6 +125,8 @@ impl Interpreter {
                 0
             };
 
+            wait_for_thread_shutdown(vm);
+
             atexit::_run_exitfuncs(vm);
 
             vm.state.finalizing.store(true, Ordering::Release);
@@ -135,6 +138,22 @@ impl Interpreter {
     }
 }
 
+/// Joins all non-daemon threads before the interpreter tears down, mirroring
+/// CPython's `wait_for_thread_shutdown`. Only runs if `threading` was ever
+/// imported -- like CPython, we don't want finalization to drag it in for
+/// programs that never used it, and daemon threads are simply abandoned.
+fn wait_for_thread_shutdown(vm: &VirtualMachine) {
+    let Ok(sys_modules) = vm.sys_module.get_attr("modules", vm) else {
+        return;
+    };
+    let Ok(threading) = sys_modules.get_item("threading", vm) else {
+        return;
+    };
+    if let Err(e) = vm.call_method(&threading, "_shutdown", ()) {
+        vm.run_unraisable(e, Some("Exception ignored in".to_owned()), threading);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
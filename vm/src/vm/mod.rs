@@ -70,6 +70,12 @@ pub struct VirtualMachine {
     pub profile_func: RefCell<PyObjectRef>,
     pub trace_func: RefCell<PyObjectRef>,
     pub use_tracing: Cell<bool>,
+    /// Fast-path breakpoint set installed by
+    /// `sys._settrace_skip_unless_breakpoint`: (filename, line number)
+    /// pairs that a bdb-style trace function actually cares about. `None`
+    /// means the fast path isn't active and every line should be offered
+    /// to the trace function as usual.
+    pub trace_skip_lines: RefCell<Option<HashSet<(String, u32)>>>,
     pub recursion_limit: Cell<usize>,
     pub(crate) signal_handlers: Option<Box<RefCell<[Option<PyObjectRef>; signal::NSIG]>>>,
     pub(crate) signal_rx: Option<signal::UserSignalReceiver>,
@@ -102,6 +108,10 @@ pub struct PyGlobalState {
     pub after_forkers_parent: PyMutex<Vec<PyObjectRef>>,
     pub int_max_str_digits: AtomicCell<usize>,
     pub switch_interval: AtomicCell<f64>,
+    /// The innermost currently-running frame of every OS thread that has one,
+    /// keyed by the same thread id `_thread.get_ident()` returns. Backs
+    /// `sys._current_frames()`; kept up to date from [`VirtualMachine::with_frame`].
+    pub thread_frames: PyMutex<HashMap<u64, FrameRef>>,
 }
 
 pub fn process_hash_secret_seed() -> u32 {
@@ -165,6 +175,7 @@ impl VirtualMachine {
             profile_func,
             trace_func,
             use_tracing: Cell::new(false),
+            trace_skip_lines: RefCell::new(None),
             recursion_limit: Cell::new(if cfg!(debug_assertions) { 256 } else { 1000 }),
             signal_handlers,
             signal_rx: None,
@@ -186,6 +197,7 @@ impl VirtualMachine {
                 after_forkers_parent: PyMutex::default(),
                 int_max_str_digits,
                 switch_interval: AtomicCell::new(0.005),
+                thread_frames: PyMutex::default(),
             }),
             initialized: false,
             recursion_depth: Cell::new(0),
@@ -506,9 +518,25 @@ impl VirtualMachine {
     ) -> PyResult<R> {
         self.with_recursion("", || {
             self.frames.borrow_mut().push(frame.clone());
+            let thread_id = crate::stdlib::thread::thread_to_id(&std::thread::current());
+            self.state
+                .thread_frames
+                .lock()
+                .insert(thread_id, frame.clone());
             let result = f(frame);
             // defer dec frame
             let _popped = self.frames.borrow_mut().pop();
+            match self.frames.borrow().last() {
+                Some(caller_frame) => {
+                    self.state
+                        .thread_frames
+                        .lock()
+                        .insert(thread_id, caller_frame.clone());
+                }
+                None => {
+                    self.state.thread_frames.lock().remove(&thread_id);
+                }
+            }
             result
         })
     }
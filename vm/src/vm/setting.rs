@@ -96,7 +96,11 @@ pub struct Settings {
     /// Environment PYTHONPATH (and RUSTPYTHONPATH)
     pub path_list: Vec<String>,
 
-    // wchar_t *home;
+    /// Environment RUSTPYTHONHOME (and PYTHONHOME): overrides `sys.prefix`/
+    /// `sys.exec_prefix` at startup, the same way CPython's PYTHONHOME
+    /// points at an alternate install tree. When set, a `lib/rustpythonX.Y`
+    /// directory under it is also added to the stdlib search path.
+    pub home: Option<String>,
     // wchar_t *platlibdir;
     /// -d command line switch
     pub debug: u8,
@@ -155,6 +159,7 @@ impl Default for Settings {
             warn_default_encoding: false,
             warnoptions: vec![],
             path_list: vec![],
+            home: None,
             argv: vec![],
             hash_seed: None,
             buffered_stdio: true,
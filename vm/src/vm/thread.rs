@@ -157,6 +157,7 @@ impl VirtualMachine {
             profile_func: RefCell::new(self.ctx.none()),
             trace_func: RefCell::new(self.ctx.none()),
             use_tracing: Cell::new(false),
+            trace_skip_lines: RefCell::new(None),
             recursion_limit: self.recursion_limit.clone(),
             signal_handlers: None,
             signal_rx: None,
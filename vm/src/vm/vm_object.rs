@@ -5,6 +5,7 @@ use crate::{
     identifier,
     object::{AsObject, PyObject, PyObjectRef, PyResult},
     stdlib::sys,
+    types::PyComparisonOp,
     vm::VirtualMachine,
 };
 
@@ -143,9 +144,26 @@ impl VirtualMachine {
                 (),
             )?,
         };
-        let items: Vec<_> = seq.try_to_value(self)?;
+        let items: Vec<PyObjectRef> = seq.try_to_value(self)?;
         let lst = PyList::from(items);
-        lst.sort(Default::default(), self)?;
+        // Sorting may fail on an unorderable mix of types; CPython's dir()
+        // swallows that and returns the list unsorted rather than raising.
+        if lst.sort(Default::default(), self).is_ok() {
+            // Once sorted, duplicates (e.g. from an overlapping __dir__ and
+            // metaclass MRO) are adjacent, so a single equality pass merges them.
+            let mut vec = lst.borrow_vec_mut();
+            let mut deduped = Vec::with_capacity(vec.len());
+            for item in vec.drain(..) {
+                let is_dup = deduped.last().is_some_and(|prev: &PyObjectRef| {
+                    prev.rich_compare_bool(&item, PyComparisonOp::Eq, self)
+                        .unwrap_or(false)
+                });
+                if !is_dup {
+                    deduped.push(item);
+                }
+            }
+            *vec = deduped;
+        }
         Ok(lst)
     }
 